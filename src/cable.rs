@@ -0,0 +1,50 @@
+//! Items related to the LinkMD cable diagnostics available via both the MIIM and SMI.
+
+impl_field_enum! {
+    /// The result of a LinkMD virtual cable test (VCT).
+    pub enum VctStatus {
+        /// The cable is normal.
+        Normal = 0b00,
+        /// An open condition was detected in the cable.
+        Open = 0b01,
+        /// A short condition was detected in the cable.
+        Short = 0b10,
+        /// The cable diagnostic test failed.
+        Failed = 0b11,
+    }
+}
+
+/// The outcome of a completed LinkMD cable diagnostic test.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct CableDiagnostics {
+    /// The result of the test.
+    pub result: VctStatus,
+    /// The raw 9-bit VCT fault count.
+    pub fault_count: u16,
+    /// The distance to the fault in centimetres, present for `Open` and `Short` results.
+    pub distance_to_fault: Option<u16>,
+}
+
+impl CableDiagnostics {
+    /// Produce the diagnostics from the raw VCT result and fault count fields.
+    pub fn from_vct(result: VctStatus, fault_count: u16) -> Self {
+        let distance_to_fault = match result {
+            VctStatus::Open | VctStatus::Short => Some(distance_to_fault_cm(fault_count)),
+            VctStatus::Normal | VctStatus::Failed => None,
+        };
+        CableDiagnostics {
+            result,
+            fault_count,
+            distance_to_fault,
+        }
+    }
+}
+
+/// Determine the distance to a cable fault in centimetres from the 9-bit VCT fault count.
+///
+/// The datasheet specifies the distance in metres as `0.4 * (fault_count - 26)`.
+pub fn distance_to_fault_cm(fault_count: u16) -> u16 {
+    (fault_count & 0x1FF).saturating_sub(26) * 40
+}
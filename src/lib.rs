@@ -80,9 +80,17 @@
 //!
 //! # Features
 //!
+//! - `async` provides the `AsyncRead` and `AsyncWrite` traits along with `_async` variants of the
+//!   read/write/modify methods for use with asynchronous MIIM and SMI interfaces.
+//! - `defmt` provides `defmt::Format` implementations.
 //! - `hash-32` provides `Hash32` implementations from the `hash32` crate.
+//! - `journal` provides the `smi::Journaled` wrapper for recording register writes into a
+//!   `heapless::Vec` for later replay. Implies `trace`.
 //! - `serde` provides `Deserialize` and `Serialize` implementations.
-//! - `ufmt` provides `ufmt::uDebug` implementations.
+//! - `trace` provides the `smi::Traced` and `miim::Traced` wrappers for reporting each register
+//!   transaction to a callback.
+//! - `ufmt` provides `ufmt::uDebug` implementations, along with `ufmt::uDisplay` for register
+//!   addresses.
 //!
 //! All of these features are **opt-in** and disabled by default.
 
@@ -91,6 +99,8 @@
 
 #[macro_use]
 mod macros;
+pub mod cable;
+pub mod link;
 pub mod miim;
 pub mod smi;
 
@@ -101,6 +111,107 @@ pub use smi::Smi;
 #[derive(Debug)]
 pub struct InvalidAddress;
 
+/// The error returned when attempting to produce a field variant from an unknown value.
+#[derive(Debug)]
+pub struct InvalidValue;
+
+/// The error returned when a slice does not have the expected length.
+#[derive(Debug)]
+pub struct InvalidLength;
+
+/// Errors that may occur while accessing a register where both the address and the underlying
+/// interface may fail.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error<E> {
+    /// An error occurred on the underlying interface.
+    Interface(E),
+    /// There is no register at the given address.
+    InvalidAddress,
+    /// A `State` was modified to hold a register at a different address than the one it was read
+    /// from.
+    AddressChanged,
+}
+
+impl<E> From<InvalidAddress> for Error<E> {
+    fn from(_: InvalidAddress) -> Self {
+        Error::InvalidAddress
+    }
+}
+
+/// Errors that may occur while polling a register for the completion of an operation.
+#[derive(Debug)]
+pub enum PollError<E> {
+    /// An error occurred on the underlying interface.
+    Interface(E),
+    /// The operation did not complete within the given number of polls.
+    Timeout,
+}
+
+/// Errors that may occur while writing a register and verifying the write via a read-back.
+#[derive(Debug)]
+pub enum VerifyError<E> {
+    /// An error occurred on the underlying interface.
+    Interface(E),
+    /// The writable bits read back from the register do not match those written.
+    Mismatch,
+}
+
+/// The access permitted to a register field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Access {
+    /// Read-only.
+    R,
+    /// Read and write.
+    RW,
+    /// Write-only.
+    W,
+}
+
+/// The kind of a transaction reported by `smi::Traced` or `miim::Traced`.
+#[cfg(feature = "trace")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum TransactionKind {
+    /// A register was read.
+    Read,
+    /// A register was written.
+    Write,
+}
+
+/// A description of a register field along with its current value, yielded by `State::fields`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The inclusive range of bits occupied by the field, lowest bit first.
+    pub bit_range: (u8, u8),
+    /// The value of the field, shifted down to bit `0`.
+    pub value: u32,
+    /// The access permitted to the field.
+    pub access: Access,
+}
+
+/// Produce a mask with all bits within the given inclusive range set.
+const fn bit_range_mask(range: core::ops::RangeInclusive<u32>) -> u32 {
+    let (lo, hi) = (*range.start(), *range.end());
+    (u32::MAX >> (31 - (hi - lo))) << lo
+}
+
+/// Fold the given bytes into a 32-bit FNV-1a hash.
+fn fnv1a(mut hash: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// The FNV-1a 32-bit offset basis.
+const FNV1A_INIT: u32 = 0x811c_9dc5;
+
 /// Allow for using bit values (1 and 0) as default values in register macro.
 trait IntoBool {
     /// Convert `self` into a `bool`.
@@ -0,0 +1,89 @@
+//! Items related to the state of a port's link, shared between the MIIM and SMI.
+
+/// The operating speed of a link.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Speed {
+    /// 10BASE-T.
+    Mbps10,
+    /// 100BASE-TX.
+    Mbps100,
+}
+
+/// The duplex mode of a link.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Duplex {
+    Half,
+    Full,
+}
+
+/// Whether or not flow control is active in either direction.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct FlowControl {
+    /// Transmit flow control is active.
+    pub tx: bool,
+    /// Receive flow control is active.
+    pub rx: bool,
+}
+
+/// A summary of the state of a port's link.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct LinkStatus {
+    /// Whether or not the link is up.
+    pub up: bool,
+    /// The operating speed.
+    pub speed: Speed,
+    /// The operating duplex mode.
+    pub duplex: Duplex,
+    /// Whether or not auto-negotiation has completed.
+    pub an_done: bool,
+    /// The active flow control.
+    pub flow_control: FlowControl,
+}
+
+/// A set of abilities advertised during auto-negotiation, either locally or by a link partner.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct LinkAbilities {
+    /// Pause (flow control) capable.
+    pub pause: bool,
+    /// 100BASE-TX full duplex capable.
+    pub full_duplex_100: bool,
+    /// 100BASE-TX half duplex capable.
+    pub half_duplex_100: bool,
+    /// 10BASE-T full duplex capable.
+    pub full_duplex_10: bool,
+    /// 10BASE-T half duplex capable.
+    pub half_duplex_10: bool,
+}
+
+/// The loopback mode of a PHY port.
+///
+/// | Mode       | MIIM                          | SMI                           |
+/// | ---------- | ----------------------------- | ----------------------------- |
+/// | `Disabled` | Clears both bits below        | Clears both bits below        |
+/// | `FarEnd`   | `Bcr::loopback`               | `Ctrl13::loopback`            |
+/// | `Remote`   | `PhySpecial::remote_loopback` | `PhySpecial::remote_loopback` |
+///
+/// Far-end loopback returns frames received on the other PHY port back out through that port via
+/// this port's PHY. Remote loopback returns frames received on this port's cable back out the same
+/// cable. Setting a mode always clears the bit for the other mode to avoid conflicts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum LoopbackMode {
+    /// Normal operation.
+    Disabled,
+    /// Far-end loopback.
+    FarEnd,
+    /// Remote loopback.
+    Remote,
+}
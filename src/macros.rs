@@ -70,6 +70,21 @@ macro_rules! impl_registers {
         impl_registers!(define_field_r_methods [$bit_index]);
     };
 
+    // Define the methods for accessing the value of a multi-bit field represented by an enum.
+    (define_field_r_methods [$bit_range:expr; enum $Enum:ident]) => {
+        impl_registers!(define_field_r_methods [$bit_range]);
+
+        /// Value of the field as a typed variant.
+        ///
+        /// Returns an `Err` if the bits do not correspond to a known variant.
+        pub fn variant(&self) -> Result<super::$Enum, crate::InvalidValue> {
+            core::convert::TryFrom::try_from(self.bits())
+        }
+    };
+    (define_field_r_methods [$bit_range:expr; $default:literal; enum $Enum:ident]) => {
+        impl_registers!(define_field_r_methods [$bit_range; enum $Enum]);
+    };
+
     // Define the methods for accessing the value associated with a field spanning multiple bits.
     (define_field_r_methods [$bit_range:expr]) => {
         /// Value of the field as raw bits.
@@ -150,6 +165,23 @@ macro_rules! impl_registers {
         }
     };
 
+    // Define the methods for writing the value of a multi-bit field represented by an enum.
+    (define_field_w_methods $Reg:ident [$bit_range:expr; enum $Enum:ident]) => {
+        impl_registers!(define_field_w_methods $Reg [$bit_range]);
+
+        /// Set the field to the given variant.
+        pub fn variant(self, variant: super::$Enum) -> &'a mut W<&'b mut $Reg> {
+            self.bits(variant.into())
+        }
+    };
+    (define_field_w_methods $Reg:ident [$bit_range:expr; $default:literal; enum $Enum:ident]) => {
+        impl_registers!(define_field_w_methods $Reg [$bit_range; enum $Enum]);
+        /// Reset the field to its default value.
+        pub fn reset(self) -> &'a mut W<&'b mut $Reg> {
+            self.bits($default)
+        }
+    };
+
     // Define the methods for writing the value associated with a field spanning multiple bits.
     (define_field_w_methods $Reg:ident [$bit_range:expr]) => {
         /// Value of the field as raw bits.
@@ -205,6 +237,9 @@ macro_rules! impl_registers {
     (field_reset_stmt $reg:ident [W $bit_index:literal; $default:literal] $field:ident) => {
         impl_registers!(field_reset_stmt $reg $field);
     };
+    (field_reset_stmt $reg:ident [W $bit_range:expr; $default:literal; enum $Enum:ident] $field:ident) => {
+        impl_registers!(field_reset_stmt $reg $field);
+    };
     (field_reset_stmt $reg:ident [W $bit_range:expr; $default:literal] $field:ident) => {
         impl_registers!(field_reset_stmt $reg $field);
     };
@@ -238,6 +273,9 @@ macro_rules! impl_registers {
             *$reg.fields.get_unchecked_mut($bit_index) = b;
         }
     };
+    (field_default_stmt $reg:ident [$bit_range:expr; $default:literal; enum $Enum:ident] $field:ident) => {
+        impl_registers!(field_default_stmt $reg [$bit_range; $default] $field);
+    };
     (field_default_stmt $reg:ident [$bit_range:expr; $default:literal] $field:ident) => {
         $reg.fields[$bit_range].store::<u8>($default);
     };
@@ -251,6 +289,91 @@ macro_rules! impl_registers {
         )*
     };
 
+    // Produce the mask of the bits occupied by each writable field.
+    (field_writable_mask [R $($tokens:tt)*]) => {
+        0
+    };
+    (field_writable_mask [RW $($tokens:tt)*]) => {
+        impl_registers!(field_mask [$($tokens)*])
+    };
+    (field_writable_mask [W $($tokens:tt)*]) => {
+        impl_registers!(field_mask [$($tokens)*])
+    };
+    (field_mask [$bit_index:literal]) => {
+        1 << $bit_index
+    };
+    (field_mask [$bit_index:literal; $($tokens:tt)*]) => {
+        1 << $bit_index
+    };
+    (field_mask [$bit_range:expr]) => {
+        crate::bit_range_mask($bit_range)
+    };
+    (field_mask [$bit_range:expr; $($tokens:tt)*]) => {
+        crate::bit_range_mask($bit_range)
+    };
+    (writable_mask $RegTy:ident $([$($tokens:tt)*] $Field:ident $field:ident,)*) => {
+        (0u32 $(| impl_registers!(field_writable_mask [$($tokens)*]))*) as $RegTy
+    };
+
+    // Produce the static description of each field.
+    (field_info [R $($tokens:tt)*] $field:ident) => {
+        impl_registers!(field_info crate::Access::R [$($tokens)*] $field)
+    };
+    (field_info [RW $($tokens:tt)*] $field:ident) => {
+        impl_registers!(field_info crate::Access::RW [$($tokens)*] $field)
+    };
+    (field_info [W $($tokens:tt)*] $field:ident) => {
+        impl_registers!(field_info crate::Access::W [$($tokens)*] $field)
+    };
+    (field_info $access:path [$bit_index:literal $(; $($tokens:tt)*)?] $field:ident) => {
+        crate::FieldInfo {
+            name: stringify!($field),
+            bit_range: ($bit_index, $bit_index),
+            value: 0,
+            access: $access,
+        }
+    };
+    (field_info $access:path [$bit_range:expr $(; $($tokens:tt)*)?] $field:ident) => {
+        crate::FieldInfo {
+            name: stringify!($field),
+            bit_range: {
+                let range = $bit_range;
+                (*range.start(), *range.end())
+            },
+            value: 0,
+            access: $access,
+        }
+    };
+    (field_infos $([$($tokens:tt)*] $Field:ident $field:ident,)*) => {
+        &[$(
+            impl_registers!(field_info [$($tokens)*] $field),
+        )*]
+    };
+
+    // Check that each field named `reserved` still holds its default value.
+    (field_reserved_check $reg:ident $Reg:ident [$access:ident $bit_index:literal; $default:literal] reserved) => {
+        if $reg.read().reserved().bit() != crate::IntoBool::into_bool($default) {
+            return Err(super::ReservedBitsViolation {
+                addr: Address::$Reg,
+                field: "reserved",
+            });
+        }
+    };
+    (field_reserved_check $reg:ident $Reg:ident [$access:ident $bit_range:expr; $default:literal] reserved) => {
+        if $reg.read().reserved().bits() != $default {
+            return Err(super::ReservedBitsViolation {
+                addr: Address::$Reg,
+                field: "reserved",
+            });
+        }
+    };
+    (field_reserved_check $reg:ident $Reg:ident [$($tokens:tt)*] $field:ident) => {};
+    (field_reserved_checks $reg:ident $Reg:ident $([$($tokens:tt)*] $Field:ident $field:ident,)*) => {
+        $(
+            impl_registers!(field_reserved_check $reg $Reg [$($tokens)*] $field);
+        )*
+    };
+
     // The statements used for the register `Debug` and `uDebug` implementations.
     (field_debug_expr $reg:ident [R $($tokens:tt)*] $field:ident) => {
         impl_registers!(field_debug_expr $reg [$($tokens)*] $field)
@@ -282,6 +405,12 @@ macro_rules! impl_registers {
             $dbg.field(stringify!($field), impl_registers!(field_debug_expr $reg [$($tokens)*] $field))?;
         )*
     };
+    (field_defmt_stmts $reg:ident $f:ident $sep:ident $([$($tokens:tt)*] $Field:ident $field:ident,)*) => {
+        $(
+            defmt::write!($f, "{=str}{=str}: {}", $sep, stringify!($field), impl_registers!(field_debug_expr $reg [$($tokens)*] $field));
+            $sep = ", ";
+        )*
+    };
 
     // Generate the index consts for the register map, with the total `COUNT` at the end.
     (map_indices $ix:expr, $IX:ident, $($IXs:ident),*) => {
@@ -338,6 +467,14 @@ macro_rules! impl_registers {
                     *self.0 = $Reg::from(bits);
                     self
                 }
+
+                /// Set only the bits of the register within `mask` to the corresponding bits of
+                /// `value`, leaving all other bits unchanged.
+                pub fn bits_masked(&mut self, value: $RegTy, mask: $RegTy) -> &mut Self {
+                    let bits: $RegTy = (*self.0).into();
+                    *self.0 = $Reg::from((bits & !mask) | (value & mask));
+                    self
+                }
             }
 
             impl_registers!(define_field_w_impls $Reg $($fields)*);
@@ -352,10 +489,39 @@ macro_rules! impl_registers {
                 pub fn write(&mut self) -> W<&mut Self> {
                     W(self)
                 }
+
+                /// Whether or not the register holds its default state.
+                pub fn is_default(&self) -> bool {
+                    *self == Self::default()
+                }
+
+                /// Check that all reserved fields still hold their documented default values.
+                ///
+                /// A violation may indicate a corrupted read, e.g. due to a flaky bus.
+                #[allow(unused_variables)]
+                pub fn validate(&self) -> Result<(), super::ReservedBitsViolation> {
+                    let reg = self;
+                    impl_registers!(field_reserved_checks reg $Reg $($fields)*);
+                    Ok(())
+                }
             }
 
+            /// The static description of each field, with a placeholder `value` of `0`.
+            pub(super) const FIELDS: &[crate::FieldInfo] = impl_registers!(field_infos $($fields)*);
+
+            // Check at compile time that every field fits within the register's width.
+            const _: () = {
+                let mut i = 0;
+                while i < FIELDS.len() {
+                    let (lo, hi) = FIELDS[i].bit_range;
+                    assert!(lo <= hi && (hi as usize) < $bits, "field exceeds register width");
+                    i += 1;
+                }
+            };
+
             impl Register for $Reg {
                 const ADDRESS: Address = Address::$Reg;
+                const WRITABLE_MASK: $RegTy = impl_registers!(writable_mask $RegTy $($fields)*);
             }
 
             #[allow(unused_mut)]
@@ -392,6 +558,20 @@ macro_rules! impl_registers {
                 }
             }
 
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for $Reg {
+                #[allow(unused_assignments, unused_mut, unused_variables)]
+                fn format(&self, f: defmt::Formatter) {
+                    let reg = self;
+                    let mut sep = " { ";
+                    defmt::write!(f, "{=str}", stringify!($Reg));
+                    impl_registers!(field_defmt_stmts reg f sep $($fields)*);
+                    if sep != " { " {
+                        defmt::write!(f, " }}");
+                    }
+                }
+            }
+
             #[cfg(feature = "ufmt")]
             impl ufmt::uDebug for $Reg {
                 fn fmt<W: ?Sized>(&self, f: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
@@ -443,17 +623,22 @@ macro_rules! impl_registers {
     (
         size_bits $bits:literal;
         data_type $RegTy:ident;
-        $($addr:literal $Reg:ident $reg:ident [ $($fields:tt)* ],)*
+        $($addr:literal $Reg:ident $reg:ident $reg_mut:ident [ $($fields:tt)* ],)*
     ) => {
         $(
             pub use $reg::$Reg;
         )*
 
-        /// The set of implemented MIIM register addresses on the KSZ8863.
+        /// The set of implemented register addresses on the KSZ8863.
+        ///
+        /// This is non-exhaustive so that registers may be added without breaking downstream
+        /// `match` expressions.
         #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
         #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
         #[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
         #[repr(u8)]
+        #[non_exhaustive]
         pub enum Address {
             $(
                 $Reg = $addr,
@@ -461,21 +646,42 @@ macro_rules! impl_registers {
         }
 
         /// A dynamic representation of a register's state.
+        ///
+        /// Like `Address`, this is non-exhaustive so that registers may be added without breaking
+        /// downstream `match` expressions.
         #[derive(Clone, Copy, Eq, Hash, PartialEq)]
         #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
         #[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+        #[non_exhaustive]
         pub enum State {
             $(
                 $Reg($Reg),
             )*
         }
 
+        /// The error returned by `validate` when a reserved field does not hold its documented
+        /// default value.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct ReservedBitsViolation {
+            /// The address of the register containing the field.
+            pub addr: Address,
+            /// The name of the field.
+            pub field: &'static str,
+        }
+
         /// A map of the state of all registers in the `impl_registers` invocation.
         #[derive(Clone, Debug, Eq, Hash, PartialEq)]
         pub struct Map {
             arr: MapArray,
         }
 
+        /// Mutable access to the state of a register within a `Map`, yielded by `Map::iter_mut`.
+        ///
+        /// Unlike `&mut State`, this does not allow for changing the register the state is
+        /// associated with.
+        pub struct StateMut<'a>(&'a mut State);
+
         /// The inner array storing all register state within a `Map`.
         ///
         /// Each register is laid out in the array in the order in which they are declared in the
@@ -500,6 +706,68 @@ macro_rules! impl_registers {
                     Self::$Reg,
                 )*
             ];
+
+            /// Produce the register address for the given byte, or `None` if there is no register at
+            /// that address.
+            ///
+            /// Unlike the `TryFrom<u8>` implementation, this may be used in const contexts.
+            pub const fn from_u8(byte: u8) -> Option<Self> {
+                match byte {
+                    $(
+                        $addr => Some(Address::$Reg),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// The index of the register within `Address::ALL`, in the range `0..Map::LEN`.
+            ///
+            /// Useful for building arrays of data associated with each register.
+            pub fn index(self) -> usize {
+                match self {
+                    $(
+                        Address::$Reg => map_index::$Reg,
+                    )*
+                }
+            }
+
+            /// The name of the register at this address, e.g. `"Gc1"`.
+            ///
+            /// This matches the name of the register type and is the string produced by the
+            /// `Display` implementation.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(
+                        Address::$Reg => stringify!($Reg),
+                    )*
+                }
+            }
+
+            /// Whether or not the register at this address contains at least one writable field.
+            pub fn is_writable(self) -> bool {
+                match self {
+                    $(
+                        Address::$Reg => <$Reg as Register>::WRITABLE_MASK != 0,
+                    )*
+                }
+            }
+
+            /// Produce an iterator yielding the addresses of all registers containing at least one
+            /// writable field.
+            pub fn writable() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().cloned().filter(|addr| addr.is_writable())
+            }
+
+            /// Produce an iterator yielding all register addresses within the inclusive range
+            /// `start..=end` in ascending order.
+            ///
+            /// Gaps between registers are skipped.
+            pub fn range(start: Self, end: Self) -> impl Iterator<Item = Self> {
+                Self::ALL
+                    .iter()
+                    .cloned()
+                    .filter(move |&addr| start <= addr && addr <= end)
+            }
         }
 
         impl State {
@@ -530,6 +798,65 @@ macro_rules! impl_registers {
                 }
             }
 
+            /// Produce an iterator describing each field of the register along with its current
+            /// value, in the order in which the fields are declared.
+            pub fn fields(&self) -> impl Iterator<Item = crate::FieldInfo> {
+                let bits = u32::from($RegTy::from(*self));
+                let fields = match *self {
+                    $(
+                        State::$Reg(_) => $reg::FIELDS,
+                    )*
+                };
+                fields.iter().map(move |field| {
+                    let (lo, hi) = field.bit_range;
+                    let mask = crate::bit_range_mask(u32::from(lo)..=u32::from(hi));
+                    crate::FieldInfo {
+                        value: (bits & mask) >> lo,
+                        ..*field
+                    }
+                })
+            }
+
+            /// Whether or not `self` and `other` are states of the same register with equal
+            /// writable bits.
+            ///
+            /// Read-only and reserved bits, such as status fields, are ignored.
+            pub fn eq_writable(&self, other: &State) -> bool {
+                let mask = self.writable_mask();
+                let (a, b) = ($RegTy::from(*self), $RegTy::from(*other));
+                self.addr() == other.addr() && a & mask == b & mask
+            }
+
+            /// Whether or not the register holds its default state.
+            pub fn is_default(&self) -> bool {
+                match *self {
+                    $(
+                        State::$Reg(ref r) => r.is_default(),
+                    )*
+                }
+            }
+
+            /// Check that all reserved fields of the register still hold their documented
+            /// default values.
+            pub fn validate(&self) -> Result<(), ReservedBitsViolation> {
+                match *self {
+                    $(
+                        State::$Reg(ref r) => r.validate(),
+                    )*
+                }
+            }
+
+            /// The mask of the bits occupied by writable fields within the register.
+            ///
+            /// A mask of `0` indicates a read-only register.
+            pub fn writable_mask(&self) -> $RegTy {
+                match *self {
+                    $(
+                        State::$Reg(_) => <$Reg as Register>::WRITABLE_MASK,
+                    )*
+                }
+            }
+
             /// Attempt to retrieve a reference to a register of type `R` from the dynamic register
             /// `State` representation.
             ///
@@ -547,6 +874,15 @@ macro_rules! impl_registers {
                 }
             }
 
+            /// Shorthand for `reg::<R>().ok()`, returning `None` if the register type does not
+            /// match.
+            pub fn as_reg<R>(&self) -> Option<&R>
+            where
+                R: 'static + Register,
+            {
+                self.reg().ok()
+            }
+
             /// Attempt to retrieve a mutable reference to a register of type `R` from the dynamic
             /// register `State` representation.
             ///
@@ -566,10 +902,13 @@ macro_rules! impl_registers {
         }
 
         impl Map {
-            /// The total number of documented registers in the TMC2209.
+            /// The total number of documented registers.
             pub const LEN: usize = map_index::COUNT;
 
             /// Read-only access to the register of the given type.
+            ///
+            /// Equivalent to `map.state(T::ADDRESS).reg::<T>()`, which cannot fail as the `Map`
+            /// always holds a state of the matching type at each address.
             pub fn reg<T>(&self) -> &T
             where
                 T: 'static + Register,
@@ -582,6 +921,18 @@ macro_rules! impl_registers {
                     .unwrap_or_else(|_| loop {})
             }
 
+            /// Read-only access to the register of the given type, via the checked
+            /// `State::as_reg` conversion.
+            ///
+            /// This always returns `Some` and is provided for symmetry with `State::as_reg`.
+            /// Prefer `reg` where a `&T` is needed directly.
+            pub fn try_reg<T>(&self) -> Option<&T>
+            where
+                T: 'static + Register,
+            {
+                self.state(T::ADDRESS).as_reg::<T>()
+            }
+
             /// Mutable access to the register of the given type.
             pub fn reg_mut<T>(&mut self) -> &mut T
             where
@@ -624,20 +975,147 @@ macro_rules! impl_registers {
                 }
             }
 
+            /// Encode the raw value of every register, ordered as in `Address::ALL`.
+            ///
+            /// This is a compact alternative to serializing each `State`, as the mapping between
+            /// addresses and indices is fixed.
+            pub fn to_bytes(&self) -> [$RegTy; map_index::COUNT] {
+                let mut bytes = [0; map_index::COUNT];
+                for (byte, &state) in bytes.iter_mut().zip(self.arr.iter()) {
+                    *byte = state.into();
+                }
+                bytes
+            }
+
+            /// Decode a `Map` from the raw value of every register, ordered as in `Address::ALL`.
+            pub fn from_bytes(bytes: &[$RegTy; map_index::COUNT]) -> Self {
+                let mut map = Self::default();
+                for (&addr, &byte) in Address::ALL.iter().zip(bytes.iter()) {
+                    map.set_state(State::from_addr_and_data(addr, byte));
+                }
+                map
+            }
+
+            /// Whether or not the writable bits of every register are equal between `self` and
+            /// `other`.
+            ///
+            /// Useful for detecting configuration drift, as read-only status fields are ignored.
+            pub fn eq_writable(&self, other: &Map) -> bool {
+                self.arr
+                    .iter()
+                    .zip(other.arr.iter())
+                    .all(|(a, b)| a.eq_writable(b))
+            }
+
+            /// A checksum over the raw value of every register.
+            ///
+            /// Useful for cheaply detecting whether any register has changed between two polls.
+            /// The checksum is a 32-bit FNV-1a hash of the little-endian register values in
+            /// `Address::ALL` order, and is stable across targets.
+            pub fn checksum(&self) -> u32 {
+                self.to_bytes().iter().fold(crate::FNV1A_INIT, |hash, byte| {
+                    crate::fnv1a(hash, &byte.to_le_bytes())
+                })
+            }
+
+            /// Read-only access to the state of the register at the given raw address.
+            ///
+            /// Returns `None` if there is no register at the given address.
+            pub fn get(&self, addr: u8) -> Option<&State> {
+                let addr: Address = core::convert::TryFrom::try_from(addr).ok()?;
+                Some(self.state(addr))
+            }
+
+            /// Mutable access to the state of the register at the given raw address.
+            ///
+            /// Returns `None` if there is no register at the given address.
+            pub fn get_mut(&mut self, addr: u8) -> Option<StateMut<'_>> {
+                let addr: Address = core::convert::TryFrom::try_from(addr).ok()?;
+                Some(StateMut(self.state_mut(addr)))
+            }
+
             /// Update the given register state.
             pub fn set_state(&mut self, state: State) {
                 *self.state_mut(state.addr()) = state;
             }
 
+            /// Produce an iterator yielding the address and state of every register.
+            ///
+            /// Registers are yielded in the order in which they are declared.
+            pub fn iter(&self) -> impl Iterator<Item = (Address, &State)> {
+                self.arr.iter().map(|state| (state.addr(), state))
+            }
+
+            /// Produce an iterator yielding the address and mutable access to the state of every
+            /// register.
+            ///
+            /// Registers are yielded in the order in which they are declared.
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = (Address, StateMut<'_>)> {
+                self.arr.iter_mut().map(|state| (state.addr(), StateMut(state)))
+            }
+
+            /// Produce an iterator yielding the address and both states of each register whose
+            /// state differs between `self` and `other`.
+            ///
+            /// Registers are yielded in the order in which they are declared.
+            pub fn diff<'a>(
+                &'a self,
+                other: &'a Map,
+            ) -> impl Iterator<Item = (Address, &'a State, &'a State)> {
+                self.arr
+                    .iter()
+                    .zip(other.arr.iter())
+                    .filter(|(a, b)| a != b)
+                    .map(|(a, b)| (a.addr(), a, b))
+            }
+
             // Generate the short-hand names for gaining direct access to typed register state.
             $(
-                // TODO: Provide immutable access too and rename mutable access to $reg_mut.
-                pub fn $reg(&mut self) -> &mut $Reg {
+                /// Read-only access to the register state.
+                pub fn $reg(&self) -> &$Reg {
+                    self.reg::<$Reg>()
+                }
+
+                /// Mutable access to the register state.
+                ///
+                /// Note: Prior to version 0.3, mutable access was named after the register alone, e.g.
+                /// `map.gc1()`, which now provides read-only access.
+                pub fn $reg_mut(&mut self) -> &mut $Reg {
                     self.reg_mut::<$Reg>()
                 }
             )*
         }
 
+        impl<'a> StateMut<'a> {
+            /// Overwrite the state with the given state.
+            ///
+            /// Returns an `Err` if the given state is associated with a different register.
+            pub fn set(&mut self, state: State) -> Result<(), crate::InvalidAddress> {
+                if state.addr() != self.0.addr() {
+                    return Err(crate::InvalidAddress);
+                }
+                *self.0 = state;
+                Ok(())
+            }
+
+            /// Attempt to retrieve a mutable reference to a register of type `R`.
+            ///
+            /// Returns an `Err` if the register type does not match.
+            pub fn reg_mut<R>(&mut self) -> Result<&mut R, crate::InvalidAddress>
+            where
+                R: 'static + Register,
+            {
+                self.0.reg_mut()
+            }
+        }
+
+        impl<'a> core::ops::Deref for StateMut<'a> {
+            type Target = State;
+            fn deref(&self) -> &Self::Target {
+                self.0
+            }
+        }
+
         impl Default for Map {
             fn default() -> Self {
                 let arr = [$(
@@ -647,6 +1125,17 @@ macro_rules! impl_registers {
             }
         }
 
+        impl<'a> core::convert::TryFrom<&'a [$RegTy]> for Map {
+            type Error = crate::InvalidLength;
+            /// Decode a `Map` from a slice of exactly `Map::LEN` raw register values, ordered as in
+            /// `Address::ALL`.
+            fn try_from(bytes: &'a [$RegTy]) -> Result<Self, Self::Error> {
+                let bytes: &[$RegTy; map_index::COUNT] =
+                    core::convert::TryFrom::try_from(bytes).map_err(|_| crate::InvalidLength)?;
+                Ok(Map::from_bytes(bytes))
+            }
+        }
+
         impl From<State> for $RegTy {
             fn from(s: State) -> Self {
                 match s {
@@ -684,9 +1173,33 @@ macro_rules! impl_registers {
         impl core::convert::TryFrom<u8> for Address {
             type Error = crate::InvalidAddress;
             fn try_from(value: u8) -> Result<Self, Self::Error> {
-                match value {
+                Address::from_u8(value).ok_or(crate::InvalidAddress)
+            }
+        }
+
+        impl core::fmt::Display for Address {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        #[cfg(feature = "ufmt")]
+        impl ufmt::uDisplay for Address {
+            fn fmt<W: ?Sized>(&self, f: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+            where
+                W: ufmt::uWrite,
+            {
+                f.write_str(self.name())
+            }
+        }
+
+        impl core::str::FromStr for Address {
+            type Err = crate::InvalidAddress;
+            /// Parse an address from the name of its register, e.g. `"Gc1"`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
                     $(
-                        $addr => Ok(Address::$Reg),
+                        stringify!($Reg) => Ok(Address::$Reg),
                     )*
                     _ => Err(crate::InvalidAddress),
                 }
@@ -717,6 +1230,63 @@ macro_rules! impl_registers {
             }
         }
 
+        #[cfg(feature = "serde")]
+        mod serde_map {
+            use super::{Address, Map, State};
+            use core::fmt;
+            use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+            use serde::ser::{Serialize, Serializer};
+
+            // Serialized as the ordered sequence of register states.
+            impl Serialize for Map {
+                fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    s.collect_seq(self.arr.iter())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Map {
+                fn deserialize<D>(d: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    d.deserialize_seq(MapVisitor)
+                }
+            }
+
+            struct MapVisitor;
+
+            impl<'de> Visitor<'de> for MapVisitor {
+                type Value = Map;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "a sequence of {} register states in address order", Map::LEN)
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut map = Map::default();
+                    for (ix, &addr) in Address::ALL.iter().enumerate() {
+                        let state: State = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(ix, &self))?;
+                        if state.addr() != addr {
+                            return Err(de::Error::custom("register state out of address order"));
+                        }
+                        map.set_state(state);
+                    }
+                    if seq.next_element::<State>()?.is_some() {
+                        return Err(de::Error::invalid_length(Map::LEN + 1, &self));
+                    }
+                    Ok(map)
+                }
+            }
+        }
+
         #[cfg(feature = "hash-32")]
         mod hash_32 {
             impl hash32::Hash for super::Address {
@@ -745,12 +1315,12 @@ macro_rules! impl_registers {
         size_bits $size_bits:literal;
         data_type $DataType:ident;
         smi_register_methods $Smi:ident $SmiReg:ident;
-        $($addr:literal $Reg:ident $reg:ident [ $($fields:tt)* ],)*
+        $($addr:literal $Reg:ident $reg:ident $reg_mut:ident [ $($fields:tt)* ],)*
     ) => {
         impl_registers! {
             size_bits $size_bits;
             data_type $DataType;
-            $($addr $Reg $reg [ $($fields)* ],)*
+            $($addr $Reg $reg $reg_mut [ $($fields)* ],)*
         }
 
         impl<T> $Smi<T> {
@@ -767,12 +1337,12 @@ macro_rules! impl_registers {
         size_bits $size_bits:literal;
         data_type $DataType:ident;
         miim_phy_register_methods $Phy:ident $PhyReg:ident;
-        $($addr:literal $Reg:ident $reg:ident [ $($fields:tt)* ],)*
+        $($addr:literal $Reg:ident $reg:ident $reg_mut:ident [ $($fields:tt)* ],)*
     ) => {
         impl_registers! {
             size_bits $size_bits;
             data_type $DataType;
-            $($addr $Reg $reg [ $($fields)* ],)*
+            $($addr $Reg $reg $reg_mut [ $($fields)* ],)*
         }
 
         impl<'miim, T> $Phy<'miim, T> {
@@ -784,3 +1354,50 @@ macro_rules! impl_registers {
         }
     };
 }
+
+/// A macro for declaring an enum representing the values of a multi-bit register field.
+///
+/// Fields annotated with `enum $Enum` within `impl_registers` expect the enum to be declared within
+/// the parent module of the register modules via this macro.
+macro_rules! impl_field_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $Enum:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $Variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+        #[repr(u8)]
+        pub enum $Enum {
+            $(
+                $(#[$variant_attr])*
+                $Variant = $value,
+            )*
+        }
+
+        impl core::convert::TryFrom<u8> for $Enum {
+            type Error = crate::InvalidValue;
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    $(
+                        $value => Ok($Enum::$Variant),
+                    )*
+                    _ => Err(crate::InvalidValue),
+                }
+            }
+        }
+
+        impl From<$Enum> for u8 {
+            fn from(variant: $Enum) -> Self {
+                variant as u8
+            }
+        }
+    };
+}
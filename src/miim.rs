@@ -6,15 +6,87 @@
 //!
 //! Each of the 8 16-bit registers are indexed via a 5-bit address, preceded by a 5-bit PHY address.
 
+#[cfg(feature = "async")]
+mod asynch;
+mod counting;
+mod id;
+mod retry;
+mod sim;
+#[cfg(feature = "trace")]
+mod trace;
+
+#[cfg(feature = "async")]
+pub use self::asynch::{AsyncRead, AsyncWrite};
+pub use self::counting::Counting;
+pub use self::id::PhyId;
+pub use self::retry::Retry;
+pub use self::sim::Sim;
+#[cfg(feature = "trace")]
+pub use self::trace::{Traced, Transaction};
+pub use crate::cable::{CableDiagnostics, VctStatus};
+pub use crate::link::{Duplex, FlowControl, LinkAbilities, LinkStatus, LoopbackMode, Speed};
 use mdio::miim::{Read, Write};
 
 /// Implemented for all 16-bit MIIM registers.
 pub trait Register: Default + From<u16> + Into<u16> {
     /// The address at which the register can be located via the MIIM interface.
     const ADDRESS: Address;
+    /// The mask of the bits occupied by writable fields within the register.
+    const WRITABLE_MASK: u16;
+    /// The mask of the writable bits that the PHY clears once the triggered operation completes,
+    /// e.g. `Bcr::restart_an`.
+    const SELF_CLEARING_MASK: u16 = self_clearing_mask(Self::ADDRESS);
+
+    /// The raw power-on value of the register.
+    fn reset_value() -> u16 {
+        Self::default().into()
+    }
+}
+
+/// The self-clearing bits of the register at the given address.
+const fn self_clearing_mask(addr: Address) -> u16 {
+    match addr {
+        // `restart_an`.
+        Address::Bcr => 1 << 9,
+        // `vct_enable`.
+        Address::LinkMd => 1 << 15,
+        _ => 0,
+    }
+}
+
+impl Address {
+    /// The mask of the self-clearing trigger bits of the register at this address.
+    ///
+    /// These bits are masked out by `Phy::write_map` so that replaying a map never re-triggers an
+    /// operation. See `Register::SELF_CLEARING_MASK`.
+    pub fn self_clearing_mask(self) -> u16 {
+        self_clearing_mask(self)
+    }
+}
+
+/// An optional extension to `miim::Read` for interfaces that can read many registers at once.
+///
+/// The default implementation falls back to a separate `read` per register. Interfaces that can
+/// pipeline or batch transactions may override `read_many` to reduce per-call overhead.
+pub trait ReadMany: Read {
+    /// Read the registers at each of the given addresses of the given PHY into `out`.
+    ///
+    /// Only the first `min(reg_addrs.len(), out.len())` registers are read.
+    fn read_many(
+        &mut self,
+        phy_addr: u8,
+        reg_addrs: &[u8],
+        out: &mut [u16],
+    ) -> Result<(), Self::Error> {
+        for (&reg_addr, data) in reg_addrs.iter().zip(out.iter_mut()) {
+            *data = self.read(phy_addr, reg_addr)?;
+        }
+        Ok(())
+    }
 }
 
 /// A higher-level wrapper around an `miim::Read` and/or `miim::Write` implementation.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Miim<T>(pub T);
 
 /// A wrapper around an `miim::Read` and/or `miim::Write` implementation for a particular PHY.
@@ -36,14 +108,31 @@ pub struct R<T>(T);
 /// A type wrapper that allows to write to the individual fields of a register.
 pub struct W<T>(T);
 
+/// The error returned by `Miim::try_phy` when the given address exceeds `MAX_PHY_ADDR`.
+#[derive(Debug)]
+pub struct InvalidPhyAddr;
+
+/// The maximum PHY address, as MIIM PHY addresses are 5 bits wide.
+pub const MAX_PHY_ADDR: u8 = 0x1F;
+
 /// The default PHY addresses of the two PHYs on the KSZ8863.
 pub const DEFAULT_PHY_ADDRS: [u8; 2] = [0x01, 0x02];
 
+/// The PHY addresses of the two PHYs on the KSZ8863, given the base address configured via the
+/// SMI `Gc13::phy_addr` field.
+///
+/// Port 1's PHY responds at `base` and port 2's at `base + 1`, wrapping within the 5-bit PHY
+/// address space. Assigning distinct bases allows for multiple KSZ8863s to share an MIIM bus.
+pub fn phy_addrs(base: u8) -> [u8; 2] {
+    [base & MAX_PHY_ADDR, base.wrapping_add(1) & MAX_PHY_ADDR]
+}
+
 impl_registers! {
     size_bits 16;
     data_type u16;
     miim_phy_register_methods Phy PhyReg;
-    0x0 Bcr bcr [
+    0x0 Bcr bcr bcr_mut [
+        // Soft reset is not supported by the KSZ8863. Reset the PHYs via the SMI `Reset` register.
         [R 15; 0] SoftReset soft_reset,
         [RW 14; 0] Loopback loopback,
         [RW 13; 0] Force100 force_100,
@@ -60,7 +149,7 @@ impl_registers! {
         [RW 1; 0] DisableTransmit disable_transmit,
         [RW 0; 0] DisableLeds disable_leds,
     ],
-    0x1 Bsr bsr [
+    0x1 Bsr bsr bsr_mut [
         [R 15; 0] CapableT4 capable_t4,
         [R 14; 1] Capable100Fd capable_100_fd,
         [R 13; 1] Capable100Hd capable_100_hd,
@@ -74,13 +163,13 @@ impl_registers! {
         [R 1; 0] JabberTest jabber_test,
         [R 0; 0] ExtendedCapable extended_capable,
     ],
-    0x2 PhyIdR1 phyidr1 [
+    0x2 PhyIdR1 phyidr1 phyidr1_mut [
         [R 0..=15; u16; 0x0022] PhyIdHigh phy_id_high,
     ],
-    0x3 PhyIdR2 phyidr2 [
+    0x3 PhyIdR2 phyidr2 phyidr2_mut [
         [RW 0..=15; u16; 0x1430] PhyIdLow phy_id_low,
     ],
-    0x4 Anar anar [
+    0x4 Anar anar anar_mut [
         [R 15; 0] NextPage next_page,
         [R 13; 0] RemoteFault remote_fault,
         [RW 10; 1] AdvPause adv_pause,
@@ -89,7 +178,7 @@ impl_registers! {
         [RW 6; 1] Adv10Fd adv_10_fd,
         [RW 5; 1] Adv10Hd adv_10_hd,
     ],
-    0x5 Anlpar anlpar [
+    0x5 Anlpar anlpar anlpar_mut [
         [R 15; 0] NextPage next_page,
         [R 10; 0] LpPause lp_pause,
         [R 8; 0] Lp100Fd lp_100_fd,
@@ -97,13 +186,13 @@ impl_registers! {
         [R 6; 0] Lp10Fd lp_10_fd,
         [R 5; 0] Lp10Hd lp_10_hd,
     ],
-    0x1D LinkMd link_md [
+    0x1D LinkMd link_md link_md_mut [
         [RW 15; 0] VctEnable vct_enable,
-        [R 13..=14; 0] VctResult vct_result,
+        [R 13..=14; 0; enum VctStatus] VctResult vct_result,
         [R 12; 0] Vct10mShort vct_10m_short,
         [R 0..=8; u16; 0] VctFaultCount vct_fault_count,
     ],
-    0x1F PhySpecial phy_special [
+    0x1F PhySpecial phy_special phy_special_mut [
         [R 5; 0] PolarityReversed polarity_reversed,
         [R 4; 0] MdixStatus mdix_status,
         [RW 3; 0] ForceLink force_link,
@@ -114,9 +203,35 @@ impl_registers! {
 
 impl<T> Miim<T> {
     /// Address a particular PHY over MIIM.
+    ///
+    /// Only the lower 5 bits of `addr` are used. See `try_phy` for a checked alternative.
     pub fn phy(&mut self, addr: u8) -> Phy<T> {
+        let addr = addr & MAX_PHY_ADDR;
         Phy { miim: self, addr }
     }
+
+    /// Address a particular PHY over MIIM.
+    ///
+    /// Returns `InvalidPhyAddr` if `addr` is greater than `MAX_PHY_ADDR`.
+    pub fn try_phy(&mut self, addr: u8) -> Result<Phy<T>, InvalidPhyAddr> {
+        if addr > MAX_PHY_ADDR {
+            return Err(InvalidPhyAddr);
+        }
+        Ok(self.phy(addr))
+    }
+
+    /// Call `f` with a `Phy` for each of the `DEFAULT_PHY_ADDRS` in turn.
+    ///
+    /// Returns early with the first error produced by `f`.
+    pub fn for_each_phy<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut Phy<'_, T>) -> Result<(), E>,
+    {
+        for &addr in DEFAULT_PHY_ADDRS.iter() {
+            f(&mut self.phy(addr))?;
+        }
+        Ok(())
+    }
 }
 
 impl<'miim, T> Phy<'miim, T> {
@@ -137,6 +252,18 @@ impl<'miim, T> Phy<'miim, T> {
         Ok(State::from_addr_and_data(addr, bits))
     }
 
+    /// Read the register with the given raw address.
+    ///
+    /// Returns `Error::InvalidAddress` without performing a read if there is no register at
+    /// the given address.
+    pub fn read_raw<E>(&mut self, addr: u8) -> Result<State, crate::Error<E>>
+    where
+        T: Read<Error = E>,
+    {
+        let addr: Address = core::convert::TryFrom::try_from(addr)?;
+        self.read(addr).map_err(crate::Error::Interface)
+    }
+
     /// Write the given register state to the register with the associated address.
     pub fn write(&mut self, state: State) -> Result<(), T::Error>
     where
@@ -146,6 +273,295 @@ impl<'miim, T> Phy<'miim, T> {
             .0
             .write(self.addr, state.addr().into(), state.into())
     }
+
+    /// Read the register of type `R` of this PHY, e.g. `read_reg::<Bcr>()`.
+    ///
+    /// Equivalent to `reg::<R>().read()`.
+    pub fn read_reg<R>(&mut self) -> Result<R, T::Error>
+    where
+        T: Read,
+        R: Register,
+    {
+        let bits = self.miim.0.read(self.addr, R::ADDRESS.into())?;
+        Ok(R::from(bits))
+    }
+
+    /// Write the given value to the register of type `R` of this PHY.
+    ///
+    /// The value is written as is, including any read-only fields.
+    pub fn write_reg<R>(&mut self, value: R) -> Result<(), T::Error>
+    where
+        T: Write,
+        R: Register,
+    {
+        self.miim
+            .0
+            .write(self.addr, R::ADDRESS.into(), value.into())
+    }
+
+    /// Read a summary of the PHY's link state.
+    ///
+    /// The MIIM registers do not report the operating speed and duplex directly. If
+    /// auto-negotiation has completed, these are resolved from the highest ability common to
+    /// `Anar` and `Anlpar`, and flow control is considered active in both directions if both link
+    /// partners advertise pause. Otherwise, the forced settings from `Bcr` are reported.
+    pub fn link_status(&mut self) -> Result<LinkStatus, T::Error>
+    where
+        T: Read,
+    {
+        let bcr = self.bcr().read()?;
+        let bsr = self.bsr().read()?;
+        let anar = self.anar().read()?;
+        let anlpar = self.anlpar().read()?;
+        let (bcr, bsr, anar, anlpar) = (bcr.read(), bsr.read(), anar.read(), anlpar.read());
+        let an_done = bsr.an_complete().bit();
+        let (speed, duplex, flow_control) = if an_done {
+            let (speed, duplex) = if anar.adv_100_fd().bit() && anlpar.lp_100_fd().bit() {
+                (Speed::Mbps100, Duplex::Full)
+            } else if anar.adv_100_hd().bit() && anlpar.lp_100_hd().bit() {
+                (Speed::Mbps100, Duplex::Half)
+            } else if anar.adv_10_fd().bit() && anlpar.lp_10_fd().bit() {
+                (Speed::Mbps10, Duplex::Full)
+            } else {
+                (Speed::Mbps10, Duplex::Half)
+            };
+            let pause = duplex == Duplex::Full && anar.adv_pause().bit() && anlpar.lp_pause().bit();
+            let flow_control = FlowControl {
+                tx: pause,
+                rx: pause,
+            };
+            (speed, duplex, flow_control)
+        } else {
+            let speed = match bcr.force_100().bit() {
+                true => Speed::Mbps100,
+                false => Speed::Mbps10,
+            };
+            let duplex = match bcr.force_fd().bit() {
+                true => Duplex::Full,
+                false => Duplex::Half,
+            };
+            (speed, duplex, FlowControl::default())
+        };
+        Ok(LinkStatus {
+            up: bsr.link_status().bit(),
+            speed,
+            duplex,
+            an_done,
+            flow_control,
+        })
+    }
+
+    /// Restart auto-negotiation via `Bcr::restart_an` and poll until `Bsr::an_complete` is set.
+    ///
+    /// The status is polled at most `max_polls` times before returning `PollError::Timeout`. On
+    /// completion, returns the abilities advertised by the link partner via `Anlpar`.
+    pub fn restart_an_and_wait<E>(
+        &mut self,
+        max_polls: usize,
+    ) -> Result<LinkAbilities, crate::PollError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.bcr()
+            .modify(|w| w.restart_an().set_bit())
+            .map_err(crate::PollError::Interface)?;
+        for _ in 0..max_polls {
+            let bsr = self.bsr().read().map_err(crate::PollError::Interface)?;
+            if bsr.read().an_complete().bit_is_set() {
+                return self.link_partner().map_err(crate::PollError::Interface);
+            }
+        }
+        Err(crate::PollError::Timeout)
+    }
+
+    /// Power down or power up the PHY via `Bcr::power_down`.
+    ///
+    /// After powering up, the link may be re-established sooner by restarting auto-negotiation
+    /// via `restart_an_and_wait`.
+    pub fn set_power_down<E>(&mut self, power_down: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.bcr().modify(|w| w.power_down().bit(power_down))
+    }
+
+    /// Disable auto-negotiation and force the given speed and duplex mode via `Bcr`.
+    pub fn force_link<E>(&mut self, speed: Speed, duplex: Duplex) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.bcr().modify(|w| {
+            w.an_enable()
+                .clear_bit()
+                .force_100()
+                .bit(speed == Speed::Mbps100)
+                .force_fd()
+                .bit(duplex == Duplex::Full)
+        })
+    }
+
+    /// Read the abilities advertised by this PHY via `Anar`.
+    pub fn advertised(&mut self) -> Result<LinkAbilities, T::Error>
+    where
+        T: Read,
+    {
+        let anar = self.anar().read()?;
+        let r = anar.read();
+        Ok(LinkAbilities {
+            pause: r.adv_pause().bit(),
+            full_duplex_100: r.adv_100_fd().bit(),
+            half_duplex_100: r.adv_100_hd().bit(),
+            full_duplex_10: r.adv_10_fd().bit(),
+            half_duplex_10: r.adv_10_hd().bit(),
+        })
+    }
+
+    /// Write the abilities advertised by this PHY to `Anar`.
+    ///
+    /// The new advertisement takes effect upon the next auto-negotiation, e.g. via
+    /// `restart_an_and_wait`.
+    pub fn set_advertised<E>(&mut self, abilities: LinkAbilities) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.anar().modify(|w| {
+            w.adv_pause()
+                .bit(abilities.pause)
+                .adv_100_fd()
+                .bit(abilities.full_duplex_100)
+                .adv_100_hd()
+                .bit(abilities.half_duplex_100)
+                .adv_10_fd()
+                .bit(abilities.full_duplex_10)
+                .adv_10_hd()
+                .bit(abilities.half_duplex_10)
+        })
+    }
+
+    /// Read the abilities advertised by the link partner via `Anlpar`.
+    pub fn link_partner(&mut self) -> Result<LinkAbilities, T::Error>
+    where
+        T: Read,
+    {
+        let anlpar = self.anlpar().read()?;
+        let r = anlpar.read();
+        Ok(LinkAbilities {
+            pause: r.lp_pause().bit(),
+            full_duplex_100: r.lp_100_fd().bit(),
+            half_duplex_100: r.lp_100_hd().bit(),
+            full_duplex_10: r.lp_10_fd().bit(),
+            half_duplex_10: r.lp_10_hd().bit(),
+        })
+    }
+
+    /// Run the LinkMD cable diagnostic test and return the decoded result.
+    ///
+    /// This sets `LinkMd::vct_enable` and polls at most `max_polls` times until the bit
+    /// self-clears, indicating the test is complete. The datasheet recommends disabling auto
+    /// MDI/MDI-X via `Bcr::disable_mdix` prior to running the test.
+    pub fn run_cable_diagnostics<E>(
+        &mut self,
+        max_polls: usize,
+    ) -> Result<CableDiagnostics, crate::PollError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.link_md()
+            .modify(|w| w.vct_enable().set_bit())
+            .map_err(crate::PollError::Interface)?;
+        for _ in 0..max_polls {
+            let link_md = self.link_md().read().map_err(crate::PollError::Interface)?;
+            let r = link_md.read();
+            if r.vct_enable().bit_is_clear() {
+                let result = r.vct_result().variant().unwrap_or(VctStatus::Failed);
+                let fault_count = r.vct_fault_count().bits();
+                return Ok(CableDiagnostics::from_vct(result, fault_count));
+            }
+        }
+        Err(crate::PollError::Timeout)
+    }
+
+    /// Whether or not a far-end fault has been detected, via `Bsr::remote_fault`.
+    ///
+    /// Far-end fault detection is only applicable in fiber mode.
+    pub fn far_end_fault(&mut self) -> Result<bool, T::Error>
+    where
+        T: Read,
+    {
+        let bsr = self.reg::<Bsr>().read()?;
+        Ok(bsr.read().remote_fault().bit_is_set())
+    }
+
+    /// Enable or disable far-end fault detection via `Bcr::disable_far_end_fault`.
+    pub fn set_far_end_fault_detection<E>(&mut self, enable: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Bcr>()
+            .modify(|w| w.disable_far_end_fault().bit(!enable))
+    }
+
+    /// Select the given loopback mode via `Bcr::loopback` and `PhySpecial::remote_loopback`.
+    pub fn set_loopback<E>(&mut self, mode: LoopbackMode) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let far_end = mode == LoopbackMode::FarEnd;
+        let remote = mode == LoopbackMode::Remote;
+        self.reg::<Bcr>().modify(|w| w.loopback().bit(far_end))?;
+        self.reg::<PhySpecial>()
+            .modify(|w| w.remote_loopback().bit(remote))
+    }
+
+    /// Read every register from this PHY, producing a snapshot of its full state.
+    ///
+    /// The snapshot may later be restored via `write_map`.
+    pub fn read_map(&mut self) -> Result<Map, T::Error>
+    where
+        T: Read,
+    {
+        let mut map = Map::default();
+        for &addr in Address::ALL {
+            map.set_state(self.read(addr)?);
+        }
+        Ok(map)
+    }
+
+    /// Read every register from this PHY in a single `ReadMany::read_many` call.
+    ///
+    /// Produces the same result as `read_map`, but allows batching interfaces to avoid a separate
+    /// transaction per register.
+    pub fn read_all(&mut self) -> Result<Map, T::Error>
+    where
+        T: ReadMany,
+    {
+        let mut addrs = [0u8; Map::LEN];
+        for (addr, &reg) in addrs.iter_mut().zip(Address::ALL) {
+            *addr = reg.into();
+        }
+        let mut bytes = [0u16; Map::LEN];
+        self.miim.0.read_many(self.addr, &addrs, &mut bytes)?;
+        Ok(Map::from_bytes(&bytes))
+    }
+
+    /// Write the state of every writable register within the given map to this PHY, in address
+    /// order.
+    ///
+    /// Read-only registers are skipped, and self-clearing trigger bits such as `Bcr::restart_an`
+    /// are always written as `0`.
+    pub fn write_map(&mut self, map: &Map) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        for (addr, state) in map.iter() {
+            if state.writable_mask() != 0 {
+                let data: u16 = (*state).into();
+                let data = data & !addr.self_clearing_mask();
+                self.miim.0.write(self.addr, addr.into(), data)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'phy, 'miim, T, R> PhyReg<'phy, 'miim, T, R>
@@ -161,13 +577,27 @@ where
         Ok(R::from(bits))
     }
 
-    /// Write to the register `R` associated with the specified PHY.
+    /// Write to the register `R` associated with the specified PHY, initialised with a default
+    /// state.
+    ///
+    /// Note that every field left untouched by `write` is written with its default value rather
+    /// than its current value, including read-only fields. Use `modify` to preserve the current
+    /// value of untouched fields.
     pub fn write<F>(&mut self, write: F) -> Result<(), T::Error>
     where
         T: Write,
         F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
     {
-        let mut reg = R::default();
+        self.write_from(R::default(), write)
+    }
+
+    /// Write to the register `R` associated with the specified PHY, initialised with the given
+    /// state.
+    pub fn write_from<F>(&mut self, mut reg: R, write: F) -> Result<(), T::Error>
+    where
+        T: Write,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
         write(&mut W(&mut reg));
         self.phy
             .miim
@@ -191,6 +621,29 @@ where
             .0
             .write(self.phy.addr, R::ADDRESS.into(), reg.into())
     }
+
+    /// Modify the register `R` associated with the specified PHY, only writing the result if it
+    /// differs from the value read.
+    ///
+    /// Returns whether or not the write occurred.
+    pub fn modify_if_changed<F, E>(&mut self, modify: F) -> Result<bool, E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        R: Copy + PartialEq,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let read: R = self.read()?;
+        let mut reg = read;
+        modify(&mut W(&mut reg));
+        if reg == read {
+            return Ok(false);
+        }
+        self.phy
+            .miim
+            .0
+            .write(self.phy.addr, R::ADDRESS.into(), reg.into())?;
+        Ok(true)
+    }
 }
 
 impl Read for Map {
@@ -201,6 +654,8 @@ impl Read for Map {
     }
 }
 
+impl ReadMany for Map {}
+
 impl Write for Map {
     type Error = crate::InvalidAddress;
     fn write(&mut self, _phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
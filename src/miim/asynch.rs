@@ -0,0 +1,103 @@
+//! Asynchronous access to the MIIM interface, enabled via the `async` feature.
+
+use super::{Address, Phy, PhyReg, Register, State, W};
+use core::future::Future;
+
+/// A trait for asynchronously reading from an MIIM interface.
+pub trait AsyncRead {
+    /// Errors that might occur on the MIIM interface.
+    type Error;
+    /// Read the data from the given register address associated with the specified PHY.
+    fn read(
+        &mut self,
+        phy_addr: u8,
+        reg_addr: u8,
+    ) -> impl Future<Output = Result<u16, Self::Error>>;
+}
+
+/// A trait for asynchronously writing to an MIIM interface.
+pub trait AsyncWrite {
+    /// Errors that might occur on the MIIM interface.
+    type Error;
+    /// Write to the register at the given address associated with the specified PHY.
+    fn write(
+        &mut self,
+        phy_addr: u8,
+        reg_addr: u8,
+        data: u16,
+    ) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+impl<'miim, T> Phy<'miim, T> {
+    /// Asynchronously read the register with the given address.
+    pub async fn read_async(&mut self, addr: Address) -> Result<State, T::Error>
+    where
+        T: AsyncRead,
+    {
+        let bits = self.miim.0.read(self.addr, addr.into()).await?;
+        Ok(State::from_addr_and_data(addr, bits))
+    }
+
+    /// Asynchronously write the given register state to the register with the associated address.
+    pub async fn write_async(&mut self, state: State) -> Result<(), T::Error>
+    where
+        T: AsyncWrite,
+    {
+        self.miim
+            .0
+            .write(self.addr, state.addr().into(), state.into())
+            .await
+    }
+}
+
+impl<'phy, 'miim, T, R> PhyReg<'phy, 'miim, T, R>
+where
+    R: Register,
+{
+    /// Asynchronously read from the register `R` associated with the specified PHY.
+    pub async fn read_async(&mut self) -> Result<R, T::Error>
+    where
+        T: AsyncRead,
+    {
+        let bits = self
+            .phy
+            .miim
+            .0
+            .read(self.phy.addr, R::ADDRESS.into())
+            .await?;
+        Ok(R::from(bits))
+    }
+
+    /// Asynchronously write to the register `R` associated with the specified PHY.
+    pub async fn write_async<F>(&mut self, write: F) -> Result<(), T::Error>
+    where
+        T: AsyncWrite,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg = R::default();
+        write(&mut W(&mut reg));
+        self.phy
+            .miim
+            .0
+            .write(self.phy.addr, R::ADDRESS.into(), reg.into())
+            .await
+    }
+
+    /// Asynchronously modify the register `R` associated with the specified PHY.
+    ///
+    /// This first reads the value from the register, delivers it to the user via the `modify`
+    /// function, and then writes the result.
+    pub async fn modify_async<F, E>(&mut self, modify: F) -> Result<(), E>
+    where
+        T: AsyncRead<Error = E> + AsyncWrite<Error = E>,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg: R = self.read_async().await?;
+        modify(&mut W(&mut reg));
+        self.phy
+            .miim
+            .0
+            .write(self.phy.addr, R::ADDRESS.into(), reg.into())
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+//! Decoding of the PHY identifier held by `PhyIdR1` and `PhyIdR2`.
+
+use super::{Phy, Read};
+
+/// The PHY identifier, decoded from `PhyIdR1` and `PhyIdR2`.
+///
+/// | Register  | Bits    | Field                   |
+/// | --------- | ------- | ----------------------- |
+/// | `PhyIdR1` | `15:0`  | OUI bits `3:18`         |
+/// | `PhyIdR2` | `15:10` | OUI bits `19:24`        |
+/// | `PhyIdR2` | `9:4`   | Manufacturer model      |
+/// | `PhyIdR2` | `3:0`   | Manufacturer revision   |
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PhyId {
+    /// The 22 bits of the organizationally unique identifier held by the registers.
+    pub oui: u32,
+    /// The 6-bit manufacturer model number.
+    pub model: u8,
+    /// The 4-bit manufacturer revision number.
+    pub revision: u8,
+}
+
+impl PhyId {
+    /// The identifier reported by the KSZ8863's PHYs, i.e. `0x0022` and `0x1430`.
+    pub const KSZ8863: Self = PhyId {
+        oui: 0x0885,
+        model: 0x03,
+        revision: 0x0,
+    };
+
+    /// Decode the identifier from the values of `PhyIdR1` and `PhyIdR2`.
+    pub fn from_regs(id1: u16, id2: u16) -> Self {
+        PhyId {
+            oui: (u32::from(id1) << 6) | u32::from(id2 >> 10),
+            model: ((id2 >> 4) & 0x3F) as u8,
+            revision: (id2 & 0xF) as u8,
+        }
+    }
+
+    /// Whether or not the OUI and model match those of the KSZ8863, ignoring the revision.
+    pub fn is_ksz8863(&self) -> bool {
+        self.oui == Self::KSZ8863.oui && self.model == Self::KSZ8863.model
+    }
+}
+
+impl<'miim, T> Phy<'miim, T> {
+    /// Read and decode the PHY identifier from `PhyIdR1` and `PhyIdR2`.
+    pub fn identify(&mut self) -> Result<PhyId, T::Error>
+    where
+        T: Read,
+    {
+        let id1 = self.phyidr1().read()?.read().phy_id_high().bits();
+        let id2 = self.phyidr2().read()?.read().phy_id_low().bits();
+        Ok(PhyId::from_regs(id1, id2))
+    }
+}
@@ -0,0 +1,87 @@
+//! An adapter that retries failed MIIM transactions.
+
+use super::{Read, ReadMany, Write};
+
+/// Wraps an `miim::Read` and/or `miim::Write` implementation, retrying each failed read or write.
+///
+/// Each transaction is attempted at most `retries + 1` times. The error of the final attempt is
+/// returned if all attempts fail.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Retry<T> {
+    inner: T,
+    retries: usize,
+}
+
+impl<T> Retry<T> {
+    /// Wrap the given interface, retrying each failed transaction up to `retries` times.
+    pub fn new(inner: T, retries: usize) -> Self {
+        Retry { inner, retries }
+    }
+
+    /// The number of times a failed transaction is retried.
+    pub fn retries(&self) -> usize {
+        self.retries
+    }
+
+    /// Access the wrapped interface.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped interface.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner interface.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for Retry<T> {
+    type Error = T::Error;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        let mut result = self.inner.read(phy_addr, reg_addr);
+        for _ in 0..self.retries {
+            if result.is_ok() {
+                break;
+            }
+            result = self.inner.read(phy_addr, reg_addr);
+        }
+        result
+    }
+}
+
+impl<T: ReadMany> ReadMany for Retry<T> {
+    /// The whole batch is retried if any of its reads fail.
+    fn read_many(
+        &mut self,
+        phy_addr: u8,
+        reg_addrs: &[u8],
+        out: &mut [u16],
+    ) -> Result<(), Self::Error> {
+        let mut result = self.inner.read_many(phy_addr, reg_addrs, out);
+        for _ in 0..self.retries {
+            if result.is_ok() {
+                break;
+            }
+            result = self.inner.read_many(phy_addr, reg_addrs, out);
+        }
+        result
+    }
+}
+
+impl<T: Write> Write for Retry<T> {
+    type Error = T::Error;
+    fn write(&mut self, phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
+        let mut result = self.inner.write(phy_addr, reg_addr, data);
+        for _ in 0..self.retries {
+            if result.is_ok() {
+                break;
+            }
+            result = self.inner.write(phy_addr, reg_addr, data);
+        }
+        result
+    }
+}
@@ -0,0 +1,90 @@
+//! A simulated MIIM interface that models some of the PHY's active register behaviour.
+
+use super::{Address, Map, Read, ReadMany, State, Write};
+use core::convert::TryFrom;
+
+/// A `Map`-backed MIIM interface that models the following register behaviour:
+///
+/// - Setting `Bcr::restart_an` completes auto-negotiation immediately, clearing the bit and
+///   setting `Bsr::an_complete` if `Bcr::an_enable` is set.
+/// - Setting `LinkMd::vct_enable` completes the cable diagnostic test immediately, clearing the
+///   bit.
+///
+/// All other writes behave as they do for `Map`. Like `Map`, the simulated state is shared by all
+/// PHY addresses.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sim {
+    map: Map,
+}
+
+impl Sim {
+    /// Simulate a PHY with the given initial register state.
+    pub fn new(map: Map) -> Self {
+        Sim { map }
+    }
+
+    /// The current register state.
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    /// Mutable access to the register state, e.g. to simulate link changes.
+    pub fn map_mut(&mut self) -> &mut Map {
+        &mut self.map
+    }
+
+    /// Unwrap the register state.
+    pub fn into_map(self) -> Map {
+        self.map
+    }
+
+    fn bits(&self, addr: Address) -> u16 {
+        (*self.map.state(addr)).into()
+    }
+
+    fn set_bits(&mut self, addr: Address, bits: u16) {
+        self.map.set_state(State::from_addr_and_data(addr, bits));
+    }
+
+    /// Apply the modelled behaviour following a write to the given register.
+    fn written(&mut self, addr: Address) {
+        const RESTART_AN: u16 = 1 << 9;
+        const AN_COMPLETE: u16 = 1 << 5;
+        const VCT_ENABLE: u16 = 1 << 15;
+        match addr {
+            Address::Bcr => {
+                let bcr = self.bits(addr);
+                if bcr & RESTART_AN != 0 {
+                    self.set_bits(addr, bcr & !RESTART_AN);
+                    if self.map.bcr().read().an_enable().bit_is_set() {
+                        let bsr = self.bits(Address::Bsr);
+                        self.set_bits(Address::Bsr, bsr | AN_COMPLETE);
+                    }
+                }
+            }
+            Address::LinkMd => {
+                let link_md = self.bits(addr);
+                self.set_bits(addr, link_md & !VCT_ENABLE);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Read for Sim {
+    type Error = crate::InvalidAddress;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        self.map.read(phy_addr, reg_addr)
+    }
+}
+
+impl ReadMany for Sim {}
+
+impl Write for Sim {
+    type Error = crate::InvalidAddress;
+    fn write(&mut self, phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
+        self.map.write(phy_addr, reg_addr, data)?;
+        self.written(Address::try_from(reg_addr)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//! An instrumentation wrapper that reports each MIIM transaction to a callback.
+
+use super::{Read, Write};
+use crate::TransactionKind;
+
+/// A single successful transaction on the MIIM interface, as reported by `Traced`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Transaction {
+    /// Whether the transaction was a read or a write.
+    pub kind: TransactionKind,
+    /// The address of the PHY.
+    pub phy_addr: u8,
+    /// The address of the register.
+    pub reg_addr: u8,
+    /// The data read from or written to the register.
+    pub data: u16,
+}
+
+/// Wraps an `miim::Read` and/or `miim::Write` implementation, calling `trace` with each
+/// successful transaction.
+///
+/// Useful for debugging bring-up without a bus analyzer.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Traced<T, F> {
+    inner: T,
+    trace: F,
+}
+
+impl<T, F> Traced<T, F>
+where
+    F: FnMut(Transaction),
+{
+    /// Wrap the given interface, calling `trace` with each successful transaction.
+    pub fn new(inner: T, trace: F) -> Self {
+        Traced { inner, trace }
+    }
+}
+
+impl<T, F> Traced<T, F> {
+    /// Access the wrapped interface.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped interface.
+    ///
+    /// Accesses made directly via the wrapped interface are not traced.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner interface.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read, F: FnMut(Transaction)> Read for Traced<T, F> {
+    type Error = T::Error;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        let data = self.inner.read(phy_addr, reg_addr)?;
+        (self.trace)(Transaction {
+            kind: TransactionKind::Read,
+            phy_addr,
+            reg_addr,
+            data,
+        });
+        Ok(data)
+    }
+}
+
+impl<T: Write, F: FnMut(Transaction)> Write for Traced<T, F> {
+    type Error = T::Error;
+    fn write(&mut self, phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
+        self.inner.write(phy_addr, reg_addr, data)?;
+        (self.trace)(Transaction {
+            kind: TransactionKind::Write,
+            phy_addr,
+            reg_addr,
+            data,
+        });
+        Ok(())
+    }
+}
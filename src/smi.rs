@@ -4,11 +4,141 @@
 //! available configuration registers.
 //!
 //! Each register is indexed via an 8-bit address.
+//!
+//! Each SMI frame uses the `00` opcode and splits the register address across the PHYAD and REGAD
+//! fields. PHYAD is `1xRRR` for a read and `0xRRR` for a write, where `RRR` holds the upper 3 bits
+//! of the register address, while REGAD holds the lower 5 bits. See `read_ctrl_bits`,
+//! `write_ctrl_bits` and the `frame` module.
+//!
+//! As the frame has no bits to spare for selecting a particular switch, SMI is point-to-point:
+//! each KSZ8863 requires its own MDIO bus for SMI access. Multiple switches may share a bus for
+//! MIIM access to their PHYs by assigning each a distinct base PHY address via
+//! `Smi::set_phy_base_addr`.
+
+#[cfg(feature = "async")]
+mod asynch;
+mod buffer;
+mod chip;
+mod config;
+mod counting;
+mod cpu_port;
+mod dscp;
+mod fiber;
+mod flow_control;
+pub mod frame;
+mod indirect;
+mod interrupt;
+#[cfg(feature = "journal")]
+mod journal;
+mod mac_addr;
+mod mac_table;
+mod mib;
+mod mirror;
+mod port;
+mod power;
+mod range;
+mod rate_limit;
+mod retry;
+mod sim;
+mod src_pvid;
+mod status;
+mod tail_tag;
+#[cfg(feature = "trace")]
+mod trace;
+mod vlan_table;
+
+#[cfg(feature = "async")]
+pub use self::asynch::{AsyncRead, AsyncWrite};
+pub use self::chip::ChipVariant;
+pub use self::config::{Config, PortConfig};
+pub use self::counting::Counting;
+pub use self::cpu_port::{CpuPortConfig, MiiMode, RmiiClock};
+pub use self::dscp::{DscpError, MAX_DSCP, MAX_DSCP_PRIORITY};
+pub use self::fiber::{FiberConfig, FiberThreshold};
+pub use self::flow_control::FlowControlConfig;
+pub use self::indirect::{Indirect, IndirectError, Table};
+pub use self::interrupt::Interrupts;
+#[cfg(feature = "journal")]
+pub use self::journal::Journaled;
+pub use self::mac_addr::Station;
+pub use self::mac_table::{DynamicMacEntry, DynamicMacTable, StaticMacEntry, StaticMacTable};
+pub use self::mib::{MibCounter, MibCounters, MibReading};
+pub use self::mirror::{Direction, MirrorConfig};
+pub use self::port::{
+    Port, PortError, PortId, PortReg, PortSet, PortSetIter, UnsupportedRegister, VlanTag,
+};
+pub use self::power::SLEEP_TIMER_UNIT_MS;
+pub use self::rate_limit::RateLimit;
+pub use self::retry::Retry;
+pub use self::sim::Sim;
+pub use self::src_pvid::InsertSrcPvidMatrix;
+pub use self::status::{MacStatus, SwitchStatus};
+pub use self::tail_tag::{decode_tail_tag, encode_tail_tag, TailTagInfo};
+#[cfg(feature = "trace")]
+pub use self::trace::{Traced, Transaction};
+pub use self::vlan_table::{VlanEntry, VlanTable};
+pub use crate::cable::{CableDiagnostics, VctStatus};
+pub use crate::link::{Duplex, FlowControl, LinkAbilities, LinkStatus, LoopbackMode, Speed};
+
+/// The maximum broadcast storm protection rate, as the rate is 11 bits wide.
+pub const MAX_BROADCAST_STORM_RATE: u16 = 0x7FF;
+
+impl Address {
+    /// Whether or not the register may be written as part of a bulk write, i.e. `write_map`,
+    /// `write_diff` or `reset_all`.
+    ///
+    /// This excludes read-only registers along with those where a write has a side effect beyond
+    /// storing the value: writing `IndirectAccessCtrl1` triggers an indirect table access using
+    /// `IndirectAccessCtrl0`, writing `Reset` may reset the switch, and writing
+    /// `LinkChangeInterrupt` clears any pending interrupts.
+    pub fn is_bulk_writable(self) -> bool {
+        match self {
+            Address::IndirectAccessCtrl0
+            | Address::IndirectAccessCtrl1
+            | Address::Reset
+            | Address::LinkChangeInterrupt => false,
+            _ => self.is_writable(),
+        }
+    }
+
+    /// The mask of the self-clearing trigger bits of the register at this address.
+    ///
+    /// These bits are masked out by bulk writes so that replaying a map never re-triggers an
+    /// operation. See `Register::SELF_CLEARING_MASK`.
+    pub fn self_clearing_mask(self) -> u8 {
+        self_clearing_mask(self)
+    }
+}
 
 /// Implemented for all 8-bit SMI registers.
 pub trait Register: Default + From<u8> + Into<u8> {
     /// The address at which the register can be located via the SMI interface.
     const ADDRESS: Address;
+    /// The mask of the bits occupied by writable fields within the register.
+    const WRITABLE_MASK: u8;
+    /// The mask of the writable bits that the device clears once the triggered operation
+    /// completes, e.g. `Reset::software` or `Gc0::flush_dynamic_mac_table`.
+    const SELF_CLEARING_MASK: u8 = self_clearing_mask(Self::ADDRESS);
+
+    /// The raw power-on value of the register.
+    fn reset_value() -> u8 {
+        Self::default().into()
+    }
+}
+
+/// The self-clearing bits of the register at the given address.
+const fn self_clearing_mask(addr: Address) -> u8 {
+    match addr {
+        // `software` and `pcs`.
+        Address::Reset => 0b0001_0001,
+        // `flush_dynamic_mac_table` and `flush_static_mac_table`.
+        Address::Gc0 => 0b0011_0000,
+        // `restart_an`.
+        Address::Port1Ctrl13 | Address::Port2Ctrl13 => 0b0010_0000,
+        // `vct_en`.
+        Address::Port1PhySpecial | Address::Port2PhySpecial => 0b0001_0000,
+        _ => 0,
+    }
 }
 
 /// A trait for reading from the KSZ8863's SMI interface.
@@ -28,6 +158,7 @@ pub trait Write {
 }
 
 /// A higher-level wrapper around an `smi::Read` and/or `smi::Write` implementation.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Smi<T>(pub T);
 
 /// A wrapper around an `miim::Read` and/or `miim::Write` implementation for a particular SMI
@@ -50,10 +181,10 @@ impl_registers! {
 
     // Chip ID and Start Switch
 
-    0x00 ChipId0 chip_id0 [
+    0x00 ChipId0 chip_id0 chip_id0_mut [
         [R 0..=7; 0x88] FamilyId family_id,
     ],
-    0x01 ChipId1 chip_id1 [
+    0x01 ChipId1 chip_id1 chip_id1_mut [
         [R 4..=7; 0x3] ChipId chip_id,
         [R 1..=3] RevisionId revision_id,
         [RW 0; 1] StartSwitch start_switch,
@@ -61,13 +192,13 @@ impl_registers! {
 
     // Global Control
 
-    0x02 Gc0 gc0 [
+    0x02 Gc0 gc0 gc0_mut [
         [RW 7; 0] NewBackOff new_back_off,
         [RW 5; 0] FlushDynamicMacTable flush_dynamic_mac_table,
         [RW 4; 0] FlushStaticMacTable flush_static_mac_table,
         [RW 3; 0] PassFlowControlPacket pass_flow_control_packet,
     ],
-    0x03 Gc1 gc1 [
+    0x03 Gc1 gc1 gc1_mut [
         [RW 7; 0] PassAllFrames pass_all_frames,
         [RW 6; 0] Port3TailTag port3_tail_tag,
         [RW 5; 1] TxFlowControl tx_flow_control,
@@ -77,7 +208,7 @@ impl_registers! {
         [RW 1; 0] FastAge fast_age,
         [RW 0; 0] AggressiveBackOff aggressive_back_off,
     ],
-    0x04 Gc2 gc2 [
+    0x04 Gc2 gc2 gc2_mut [
         [RW 7; 1] UnicastPortVlanMismatchDiscard unicast_port_vlan_mismatch_discard,
         [RW 6; 1] MulticastStormProtectionDisable multicast_storm_protection_disable,
         [RW 5; 1] BackPressureMode back_pressure_mode,
@@ -86,51 +217,51 @@ impl_registers! {
         [RW 2; 0] HugePacketSupport huge_packet_support,
         [RW 1; 0] LegalMaxPacketSizeCheck legal_max_packet_size_check,
     ],
-    0x05 Gc3 gc3 [
+    0x05 Gc3 gc3 gc3_mut [
         [RW 7; 0] Vlan vlan,
         [RW 6; 0] IgmpSnoop igmp_snoop,
         [RW 3; 0] WeightedFairQueue weighted_fair_queue,
         [RW 0; 0] SniffMode sniff_mode,
     ],
-    0x06 Gc4 gc4 [
+    0x06 Gc4 gc4 gc4_mut [
         [RW 6; 0] MiiHdMode mii_hd_mode,
         [RW 5; 0] MiiFlowCtrl mii_flow_ctrl,
         [RW 4; 1] Mii10Bt mii_10_bt,
         [RW 3; 0] NullVidReplacement null_vid_replacement,
         [RW 0..=2; 0] BroadcastStormProtectionRateHigh broadcast_storm_protection_rate_high,
     ],
-    0x07 Gc5 gc5 [
+    0x07 Gc5 gc5 gc5_mut [
         [RW 0..=7; 0x63] BroadcastStormProtectionRateLow broadcast_storm_protection_rate_low,
     ],
-    0x0B Gc9 gc9 [
-        [RW 6..=7; 0b10] CpuIfaceClk cpu_iface_clk,
+    0x0B Gc9 gc9 gc9_mut [
+        [RW 6..=7; 0b10; enum CpuIfaceClk] CpuIfaceClk cpu_iface_clk,
         [R 2..=3; 0b10] Reserved reserved,
     ],
-    0x0C Gc10 gc10 [
-        [RW 6..=7; 0b01] Tag0x3 tag_0x3,
-        [RW 4..=5; 0b01] Tag0x2 tag_0x2,
-        [RW 2..=3; 0b00] Tag0x1 tag_0x1,
-        [RW 0..=1; 0b00] Tag0x0 tag_0x0,
+    0x0C Gc10 gc10 gc10_mut [
+        [RW 6..=7; 0b01; enum Queue] Tag0x3 tag_0x3,
+        [RW 4..=5; 0b01; enum Queue] Tag0x2 tag_0x2,
+        [RW 2..=3; 0b00; enum Queue] Tag0x1 tag_0x1,
+        [RW 0..=1; 0b00; enum Queue] Tag0x0 tag_0x0,
     ],
-    0x0D Gc11 gc11 [
-        [RW 6..=7; 0b11] Tag0x7 tag_0x7,
-        [RW 4..=5; 0b11] Tag0x6 tag_0x6,
-        [RW 2..=3; 0b10] Tag0x5 tag_0x5,
-        [RW 0..=1; 0b10] Tag0x4 tag_0x4,
+    0x0D Gc11 gc11 gc11_mut [
+        [RW 6..=7; 0b11; enum Queue] Tag0x7 tag_0x7,
+        [RW 4..=5; 0b11; enum Queue] Tag0x6 tag_0x6,
+        [RW 2..=3; 0b10; enum Queue] Tag0x5 tag_0x5,
+        [RW 0..=1; 0b10; enum Queue] Tag0x4 tag_0x4,
     ],
-    0x0E Gc12 gc12 [
+    0x0E Gc12 gc12 gc12_mut [
         [RW 7; 0] UnknownPacketDefaultPortEnable unknown_packet_default_port_enable,
         [RW 6; 1] DriveStrength drive_strength,
         [RW 0..=2; 0b111] UnknownPacketDefaultPort unknown_packet_default_port,
     ],
-    0x0F Gc13 gc13 [
+    0x0F Gc13 gc13 gc13_mut [
         [RW 3..=7; 0b00001] PhyAddr phy_addr,
     ],
 
     // Port Control
 
     // Port 1
-    0x10 Port1Ctrl0 port1_ctrl0 [
+    0x10 Port1Ctrl0 port1_ctrl0 port1_ctrl0_mut [
         [RW 7; 0] BroadcastStormProtection broadcast_storm_protection,
         [RW 6; 0] DiffServPriorityClassification diff_serv_priority_classification,
         [RW 5; 0] IeeePriorityClassification ieee_priority_classification,
@@ -139,7 +270,7 @@ impl_registers! {
         [RW 1; 0] TagRemoval tag_removal,
         [RW 0; 0] TxqSplitEnable txq_split,
     ],
-    0x11 Port1Ctrl1 port1_ctrl1 [
+    0x11 Port1Ctrl1 port1_ctrl1 port1_ctrl1_mut [
         [RW 7; 0] SnifferPort sniffer_port,
         [RW 6; 0] ReceiveSniff receive_sniff,
         [RW 5; 0] TransmitSniff transmit_sniff,
@@ -147,64 +278,64 @@ impl_registers! {
         [RW 3; 0] UserPriorityCeiling user_priority_ceiling,
         [RW 0..=2; 0b111] PortVlanMembership port_vlan_membership,
     ],
-    0x12 Port1Ctrl2 port1_ctrl2 [
+    0x12 Port1Ctrl2 port1_ctrl2 port1_ctrl2_mut [
         [RW 7; 0] Enable2QueueSplitTx enable_2_queue_split_tx,
         [RW 6; 0] IngressVlanFiltering ingress_vlan_filtering,
         [RW 5; 0] DiscardNonPvidPackets discard_non_pvid_packets,
-        [RW 4] ForceFlowControl force_flow_control,
+        [RW 4; 0] ForceFlowControl force_flow_control,
         [RW 3; 0] BackPressure back_pressure,
         [RW 2; 1] Transmit transmit,
         [RW 1; 1] Receive receive,
         [RW 0; 0] LearningDisable learning_disable,
     ],
-    0x13 Port1Ctrl3 port1_ctrl3 [
+    0x13 Port1Ctrl3 port1_ctrl3 port1_ctrl3_mut [
         [RW 0..=7; 0x00] DefaultTag15_8 default_tag_15_8,
     ],
-    0x14 Port1Ctrl4 port1_ctrl4 [
+    0x14 Port1Ctrl4 port1_ctrl4 port1_ctrl4_mut [
         [RW 0..=7; 0x01] DefaultTag7_0 default_tag_7_0,
     ],
-    0x15 Port1Ctrl5 port1_ctrl5 [
+    0x15 Port1Ctrl5 port1_ctrl5 port1_ctrl5_mut [
         [RW 7; 0] Port3MiiModeSelection port3_mii_mode_selection,
         [RW 6; 0] SelfAddrFilteringEnableMaca1 self_addr_filtering_enable_maca1,
         [RW 5; 0] SelfAddrFilteringEnableMaca2 self_addr_filtering_enable_maca2,
         [RW 4; 0] DropIngressTaggedFrame dropped_ingress_tagged_frame,
-        [RW 2..=3; 0b00] LimitMode limit_mode,
+        [RW 2..=3; 0b00; enum LimitMode] LimitMode limit_mode,
         [RW 1; 0] CoungIfg count_ifg,
         [RW 0; 0] CoungPre count_pre,
     ],
-    0x16 Port1Q0IngressRateLimit port1_q0_ingress_rate_limit [
+    0x16 Port1Q0IngressRateLimit port1_q0_ingress_rate_limit port1_q0_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x17 Port1Q1IngressRateLimit port1_q1_ingress_rate_limit [
+    0x17 Port1Q1IngressRateLimit port1_q1_ingress_rate_limit port1_q1_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x18 Port1Q2IngressRateLimit port1_q2_ingress_rate_limit [
+    0x18 Port1Q2IngressRateLimit port1_q2_ingress_rate_limit port1_q2_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x19 Port1Q3IngressRateLimit port1_q3_ingress_rate_limit [
+    0x19 Port1Q3IngressRateLimit port1_q3_ingress_rate_limit port1_q3_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x1A Port1PhySpecial port1_phy_special [
-        [R 5..=6; 0] VctResult vct_result,
+    0x1A Port1PhySpecial port1_phy_special port1_phy_special_mut [
+        [R 5..=6; 0; enum VctStatus] VctResult vct_result,
         [RW 4; 0] VctEn vct_en,
         [RW 3; 0] ForceLink force_link,
         [RW 1; 0] RemoteLoopback remote_loopback,
         [R 0; 0] VctFaultCount8 vct_fault_count8,
     ],
-    0x1B Port1LinkMdResult port1_link_md_result [
+    0x1B Port1LinkMdResult port1_link_md_result port1_link_md_result_mut [
         [R 0..=7; 0] VctFaultCount7_0 vct_fault_count7_0,
     ],
-    0x1C Port1Ctrl12 port1_ctrl12 [
-        [RW 7] AnEnable an_enable,
-        [RW 6] ForceSpeed force_speed,
-        [RW 5] ForceDuplex force_duplex,
+    0x1C Port1Ctrl12 port1_ctrl12 port1_ctrl12_mut [
+        [RW 7; 1] AnEnable an_enable,
+        [RW 6; 1] ForceSpeed force_speed,
+        [RW 5; 1] ForceDuplex force_duplex,
         [RW 4; 1] AdvFlowCtrl adv_flow_ctrl,
         [RW 3; 1] Adv100Fd adv_100_fd,
         [RW 2; 1] Adv100Hd adv_100_hd,
         [RW 1; 1] Adv10Fd adv_10_fd,
         [RW 0; 1] Adv10Hd adv_10_hd,
     ],
-    0x1D Port1Ctrl13 port1_ctrl13 [
+    0x1D Port1Ctrl13 port1_ctrl13 port1_ctrl13_mut [
         [RW 7; 0] LedOff led_off,
         [RW 6; 0] DisableTx disable_tx,
         [RW 5; 0] RestartAn restart_an,
@@ -214,7 +345,7 @@ impl_registers! {
         [RW 1; 0] ForceMdi force_mdi,
         [RW 0; 0] Loopback loopback,
     ],
-    0x1E Port1Status0 port1_status0 [
+    0x1E Port1Status0 port1_status0 port1_status0_mut [
         [R 7; 0] MdixStatus mdix_status,
         [R 6; 0] AnDone an_done,
         [R 5; 0] LinkGood link_good,
@@ -224,7 +355,7 @@ impl_registers! {
         [R 1; 0] Partner10Fd partner_10_fd,
         [R 0; 0] Partner10Hd partner_10_hd,
     ],
-    0x1F Port1Status1 port1_status1 [
+    0x1F Port1Status1 port1_status1 port1_status1_mut [
         [R 7; 1] HpMdix hp_mdix,
         [R 5; 0] PolarityReversed polarity_reversed,
         [R 4; 0] TxFlowCtrl tx_flow_ctrl,
@@ -235,7 +366,7 @@ impl_registers! {
     ],
 
     // Port 2
-    0x20 Port2Ctrl0 port2_ctrl0 [
+    0x20 Port2Ctrl0 port2_ctrl0 port2_ctrl0_mut [
         [RW 7; 0] BroadcastStormProtection broadcast_storm_protection,
         [RW 6; 0] DiffServPriorityClassification diff_serv_priority_classification,
         [RW 5; 0] IeeePriorityClassification ieee_priority_classification,
@@ -244,7 +375,7 @@ impl_registers! {
         [RW 1; 0] TagRemoval tag_removal,
         [RW 0; 0] TxqSplitEnable txq_split,
     ],
-    0x21 Port2Ctrl1 port2_ctrl1 [
+    0x21 Port2Ctrl1 port2_ctrl1 port2_ctrl1_mut [
         [RW 7; 0] SnifferPort sniffer_port,
         [RW 6; 0] ReceiveSniff receive_sniff,
         [RW 5; 0] TransmitSniff transmit_sniff,
@@ -252,64 +383,64 @@ impl_registers! {
         [RW 3; 0] UserPriorityCeiling user_priority_ceiling,
         [RW 0..=2; 0b111] PortVlanMembership port_vlan_membership,
     ],
-    0x22 Port2Ctrl2 port2_ctrl2 [
+    0x22 Port2Ctrl2 port2_ctrl2 port2_ctrl2_mut [
         [RW 7; 0] Enable2QueueSplitTx enable_2_queue_split_tx,
         [RW 6; 0] IngressVlanFiltering ingress_vlan_filtering,
         [RW 5; 0] DiscardNonPvidPackets discard_non_pvid_packets,
-        [RW 4] ForceFlowControl force_flow_control,
+        [RW 4; 0] ForceFlowControl force_flow_control,
         [RW 3; 0] BackPressure back_pressure,
         [RW 2; 1] Transmit transmit,
         [RW 1; 1] Receive receive,
         [RW 0; 0] LearningDisable learning_disable,
     ],
-    0x23 Port2Ctrl3 port2_ctrl3 [
+    0x23 Port2Ctrl3 port2_ctrl3 port2_ctrl3_mut [
         [RW 0..=7; 0x00] DefaultTag15_8 default_tag_15_8,
     ],
-    0x24 Port2Ctrl4 port2_ctrl4 [
+    0x24 Port2Ctrl4 port2_ctrl4 port2_ctrl4_mut [
         [RW 0..=7; 0x01] DefaultTag7_0 default_tag_7_0,
     ],
-    0x25 Port2Ctrl5 port2_ctrl5 [
+    0x25 Port2Ctrl5 port2_ctrl5 port2_ctrl5_mut [
         [RW 7; 0] Port3MiiModeSelection port3_mii_mode_selection,
         [RW 6; 0] SelfAddrFilteringEnableMaca1 self_addr_filtering_enable_maca1,
         [RW 5; 0] SelfAddrFilteringEnableMaca2 self_addr_filtering_enable_maca2,
         [RW 4; 0] DropIngressTaggedFrame dropped_ingress_tagged_frame,
-        [RW 2..=3; 0b00] LimitMode limit_mode,
+        [RW 2..=3; 0b00; enum LimitMode] LimitMode limit_mode,
         [RW 1; 0] CoungIfg count_ifg,
         [RW 0; 0] CoungPre count_pre,
     ],
-    0x26 Port2Q0IngressRateLimit port2_q0_ingress_rate_limit [
+    0x26 Port2Q0IngressRateLimit port2_q0_ingress_rate_limit port2_q0_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x27 Port2Q1IngressRateLimit port2_q1_ingress_rate_limit [
+    0x27 Port2Q1IngressRateLimit port2_q1_ingress_rate_limit port2_q1_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x28 Port2Q2IngressRateLimit port2_q2_ingress_rate_limit [
+    0x28 Port2Q2IngressRateLimit port2_q2_ingress_rate_limit port2_q2_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x29 Port2Q3IngressRateLimit port2_q3_ingress_rate_limit [
+    0x29 Port2Q3IngressRateLimit port2_q3_ingress_rate_limit port2_q3_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x2A Port2PhySpecial port2_phy_special [
-        [R 5..=6; 0] VctResult vct_result,
+    0x2A Port2PhySpecial port2_phy_special port2_phy_special_mut [
+        [R 5..=6; 0; enum VctStatus] VctResult vct_result,
         [RW 4; 0] VctEn vct_en,
         [RW 3; 0] ForceLink force_link,
         [RW 1; 0] RemoteLoopback remote_loopback,
         [R 0; 0] VctFaultCount8 vct_fault_count8,
     ],
-    0x2B Port2LinkMdResult port2_link_md_result [
+    0x2B Port2LinkMdResult port2_link_md_result port2_link_md_result_mut [
         [R 0..=7; 0] VctFaultCount7_0 vct_fault_count7_0,
     ],
-    0x2C Port2Ctrl12 port2_ctrl12 [
-        [RW 7] AnEnable an_enable,
-        [RW 6] ForceSpeed force_speed,
-        [RW 5] ForceDuplex force_duplex,
+    0x2C Port2Ctrl12 port2_ctrl12 port2_ctrl12_mut [
+        [RW 7; 1] AnEnable an_enable,
+        [RW 6; 1] ForceSpeed force_speed,
+        [RW 5; 1] ForceDuplex force_duplex,
         [RW 4; 1] AdvFlowCtrl adv_flow_ctrl,
         [RW 3; 1] Adv100Fd adv_100_fd,
         [RW 2; 1] Adv100Hd adv_100_hd,
         [RW 1; 1] Adv10Fd adv_10_fd,
         [RW 0; 1] Adv10Hd adv_10_hd,
     ],
-    0x2D Port2Ctrl13 port2_ctrl13 [
+    0x2D Port2Ctrl13 port2_ctrl13 port2_ctrl13_mut [
         [RW 7; 0] LedOff led_off,
         [RW 6; 0] DisableTx disable_tx,
         [RW 5; 0] RestartAn restart_an,
@@ -319,7 +450,7 @@ impl_registers! {
         [RW 1; 0] ForceMdi force_mdi,
         [RW 0; 0] Loopback loopback,
     ],
-    0x2E Port2Status0 port2_status0 [
+    0x2E Port2Status0 port2_status0 port2_status0_mut [
         [R 7; 0] MdixStatus mdix_status,
         [R 6; 0] AnDone an_done,
         [R 5; 0] LinkGood link_good,
@@ -329,7 +460,7 @@ impl_registers! {
         [R 1; 0] Partner10Fd partner_10_fd,
         [R 0; 0] Partner10Hd partner_10_hd,
     ],
-    0x2F Port2Status1 port2_status1 [
+    0x2F Port2Status1 port2_status1 port2_status1_mut [
         [R 7; 1] HpMdix hp_mdix,
         [R 5; 0] PolarityReversed polarity_reversed,
         [R 4; 0] TxFlowCtrl tx_flow_ctrl,
@@ -340,7 +471,7 @@ impl_registers! {
     ],
 
     // Port 3
-    0x30 Port3Ctrl0 port3_ctrl0 [
+    0x30 Port3Ctrl0 port3_ctrl0 port3_ctrl0_mut [
         [RW 7; 0] BroadcastStormProtection broadcast_storm_protection,
         [RW 6; 0] DiffServPriorityClassification diff_serv_priority_classification,
         [RW 5; 0] IeeePriorityClassification ieee_priority_classification,
@@ -349,7 +480,7 @@ impl_registers! {
         [RW 1; 0] TagRemoval tag_removal,
         [RW 0; 0] TxqSplitEnable txq_split,
     ],
-    0x31 Port3Ctrl1 port3_ctrl1 [
+    0x31 Port3Ctrl1 port3_ctrl1 port3_ctrl1_mut [
         [RW 7; 0] SnifferPort sniffer_port,
         [RW 6; 0] ReceiveSniff receive_sniff,
         [RW 5; 0] TransmitSniff transmit_sniff,
@@ -357,44 +488,45 @@ impl_registers! {
         [RW 3; 0] UserPriorityCeiling user_priority_ceiling,
         [RW 0..=2; 0b111] PortVlanMembership port_vlan_membership,
     ],
-    0x32 Port3Ctrl2 port3_ctrl2 [
+    0x32 Port3Ctrl2 port3_ctrl2 port3_ctrl2_mut [
         [RW 7; 0] Enable2QueueSplitTx enable_2_queue_split_tx,
         [RW 6; 0] IngressVlanFiltering ingress_vlan_filtering,
         [RW 5; 0] DiscardNonPvidPackets discard_non_pvid_packets,
+        [RW 4; 0] ForceFlowControl force_flow_control,
         [RW 3; 0] BackPressure back_pressure,
         [RW 2; 1] Transmit transmit,
         [RW 1; 1] Receive receive,
         [RW 0; 0] LearningDisable learning_disable,
     ],
-    0x33 Port3Ctrl3 port3_ctrl3 [
+    0x33 Port3Ctrl3 port3_ctrl3 port3_ctrl3_mut [
         [RW 0..=7; 0x00] DefaultTag15_8 default_tag_15_8,
     ],
-    0x34 Port3Ctrl4 port3_ctrl4 [
+    0x34 Port3Ctrl4 port3_ctrl4 port3_ctrl4_mut [
         [RW 0..=7; 0x01] DefaultTag7_0 default_tag_7_0,
     ],
-    0x35 Port3Ctrl5 port3_ctrl5 [
+    0x35 Port3Ctrl5 port3_ctrl5 port3_ctrl5_mut [
         [RW 7; 0] Port3MiiModeSelection port3_mii_mode_selection,
         [RW 6; 0] SelfAddrFilteringEnableMaca1 self_addr_filtering_enable_maca1,
         [RW 5; 0] SelfAddrFilteringEnableMaca2 self_addr_filtering_enable_maca2,
         [RW 4; 0] DropIngressTaggedFrame dropped_ingress_tagged_frame,
-        [RW 2..=3; 0b00] LimitMode limit_mode,
+        [RW 2..=3; 0b00; enum LimitMode] LimitMode limit_mode,
         [RW 1; 0] CoungIfg count_ifg,
         [RW 0; 0] CoungPre count_pre,
     ],
-    0x36 Port3Q0IngressRateLimit port3_q0_ingress_rate_limit [
+    0x36 Port3Q0IngressRateLimit port3_q0_ingress_rate_limit port3_q0_ingress_rate_limit_mut [
         [RW 7; 0] RmiiRefclkInvert rmii_refclk_invert,
         [RW 0..=6; 0] Limit limit,
     ],
-    0x37 Port3Q1IngressRateLimit port3_q1_ingress_rate_limit [
+    0x37 Port3Q1IngressRateLimit port3_q1_ingress_rate_limit port3_q1_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x38 Port3Q2IngressRateLimit port3_q2_ingress_rate_limit [
+    0x38 Port3Q2IngressRateLimit port3_q2_ingress_rate_limit port3_q2_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x39 Port3Q3IngressRateLimit port3_q3_ingress_rate_limit [
+    0x39 Port3Q3IngressRateLimit port3_q3_ingress_rate_limit port3_q3_ingress_rate_limit_mut [
         [RW 0..=6; 0] Limit limit,
     ],
-    0x3F Port3Status1 port3_status1 [
+    0x3F Port3Status1 port3_status1 port3_status1_mut [
         [R 4; 0] TxFlowCtrl tx_flow_ctrl,
         [R 3; 0] RxFlowCtrl rx_flow_ctrl,
         [R 2; 0] OperationSpeed operation_speed,
@@ -403,258 +535,297 @@ impl_registers! {
 
     // Reset
 
-    0x43 Reset reset [
+    0x43 Reset reset reset_mut [
         [RW 4; 0] Software software,
         [RW 0; 0] Pcs pcs,
     ],
 
     // Advanced Control Registers
 
-    0x60 TosPriorityCtrl0 tos_priority_ctrl_0 [
+    0x60 TosPriorityCtrl0 tos_priority_ctrl_0 tos_priority_ctrl_0_mut [
         [RW 0..=7; 0] Dscp0_7 dscp0_7,
     ],
-    0x61 TosPriorityCtrl1 tos_priority_ctrl_1 [
+    0x61 TosPriorityCtrl1 tos_priority_ctrl_1 tos_priority_ctrl_1_mut [
         [RW 0..=7; 0] Dscp8_15 dscp8_15,
     ],
-    0x62 TosPriorityCtrl2 tos_priority_ctrl_2 [
+    0x62 TosPriorityCtrl2 tos_priority_ctrl_2 tos_priority_ctrl_2_mut [
         [RW 0..=7; 0] Dscp16_23 dscp16_23,
     ],
-    0x63 TosPriorityCtrl3 tos_priority_ctrl_3 [
+    0x63 TosPriorityCtrl3 tos_priority_ctrl_3 tos_priority_ctrl_3_mut [
         [RW 0..=7; 0] Dscp24_31 dscp24_31,
     ],
-    0x64 TosPriorityCtrl4 tos_priority_ctrl_4 [
+    0x64 TosPriorityCtrl4 tos_priority_ctrl_4 tos_priority_ctrl_4_mut [
         [RW 0..=7; 0] Dscp32_39 dscp32_39,
     ],
-    0x65 TosPriorityCtrl5 tos_priority_ctrl_5 [
+    0x65 TosPriorityCtrl5 tos_priority_ctrl_5 tos_priority_ctrl_5_mut [
         [RW 0..=7; 0] Dscp40_47 dscp40_47,
     ],
-    0x66 TosPriorityCtrl6 tos_priority_ctrl_6 [
+    0x66 TosPriorityCtrl6 tos_priority_ctrl_6 tos_priority_ctrl_6_mut [
         [RW 0..=7; 0] Dscp48_55 dscp48_55,
     ],
-    0x67 TosPriorityCtrl7 tos_priority_ctrl_7 [
+    0x67 TosPriorityCtrl7 tos_priority_ctrl_7 tos_priority_ctrl_7_mut [
         [RW 0..=7; 0] Dscp56_63 dscp56_63,
     ],
-    0x68 TosPriorityCtrl8 tos_priority_ctrl_8 [
+    0x68 TosPriorityCtrl8 tos_priority_ctrl_8 tos_priority_ctrl_8_mut [
         [RW 0..=7; 0] Dscp64_71 dscp64_71,
     ],
-    0x69 TosPriorityCtrl9 tos_priority_ctrl_9 [
+    0x69 TosPriorityCtrl9 tos_priority_ctrl_9 tos_priority_ctrl_9_mut [
         [RW 0..=7; 0] Dscp72_79 dscp72_79,
     ],
-    0x6A TosPriorityCtrl10 tos_priority_ctrl_10 [
+    0x6A TosPriorityCtrl10 tos_priority_ctrl_10 tos_priority_ctrl_10_mut [
         [RW 0..=7; 0] Dscp80_87 dscp80_87,
     ],
-    0x6B TosPriorityCtrl11 tos_priority_ctrl_11 [
+    0x6B TosPriorityCtrl11 tos_priority_ctrl_11 tos_priority_ctrl_11_mut [
         [RW 0..=7; 0] Dscp88_95 dscp88_95,
     ],
-    0x6C TosPriorityCtrl12 tos_priority_ctrl_12 [
+    0x6C TosPriorityCtrl12 tos_priority_ctrl_12 tos_priority_ctrl_12_mut [
         [RW 0..=7; 0] Dscp96_103 dscp96_103,
     ],
-    0x6D TosPriorityCtrl13 tos_priority_ctrl_13 [
+    0x6D TosPriorityCtrl13 tos_priority_ctrl_13 tos_priority_ctrl_13_mut [
         [RW 0..=7; 0] Dscp104_111 dscp104_111,
     ],
-    0x6E TosPriorityCtrl14 tos_priority_ctrl_14 [
+    0x6E TosPriorityCtrl14 tos_priority_ctrl_14 tos_priority_ctrl_14_mut [
         [RW 0..=7; 0] Dscp112_119 dscp112_119,
     ],
-    0x6F TosPriorityCtrl15 tos_priority_ctrl_15 [
+    0x6F TosPriorityCtrl15 tos_priority_ctrl_15 tos_priority_ctrl_15_mut [
         [RW 0..=7; 0] Dscp120_127 dscp120_127,
     ],
 
-    0x70 MacAddr0 mac_addr_0 [
+    0x70 MacAddr0 mac_addr_0 mac_addr_0_mut [
         [RW 0..=7; 0x00] Data data,
     ],
-    0x71 MacAddr1 mac_addr_1 [
+    0x71 MacAddr1 mac_addr_1 mac_addr_1_mut [
         [RW 0..=7; 0x10] Data data,
     ],
-    0x72 MacAddr2 mac_addr_2 [
+    0x72 MacAddr2 mac_addr_2 mac_addr_2_mut [
         [RW 0..=7; 0xA1] Data data,
     ],
-    0x73 MacAddr3 mac_addr_3 [
+    0x73 MacAddr3 mac_addr_3 mac_addr_3_mut [
         [RW 0..=7; 0xFF] Data data,
     ],
-    0x74 MacAddr4 mac_addr_4 [
+    0x74 MacAddr4 mac_addr_4 mac_addr_4_mut [
         [RW 0..=7; 0xFF] Data data,
     ],
-    0x75 MacAddr5 mac_addr_5 [
+    0x75 MacAddr5 mac_addr_5 mac_addr_5_mut [
         [RW 0..=7; 0xFF] Data data,
     ],
 
-    0x76 UserDef1 user_def1 [
+    0x76 UserDef1 user_def1 user_def1_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x77 UserDef2 user_def2 [
+    0x77 UserDef2 user_def2 user_def2_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x78 UserDef3 user_def3 [
+    0x78 UserDef3 user_def3 user_def3_mut [
         [RW 0..=7; 0] Data data,
     ],
 
-    0x79 IndirectAccessCtrl0 indirect_access_ctrl0 [
+    0x79 IndirectAccessCtrl0 indirect_access_ctrl0 indirect_access_ctrl0_mut [
         [RW 4; 0] ReadHighWriteLow read_high_write_low,
         [RW 2..=3; 0] TableSelect table_select,
         [RW 0..=1; 0] IndirectAddrHigh indirect_addr_high,
     ],
-    0x7A IndirectAccessCtrl1 indirect_access_ctrl1 [
+    0x7A IndirectAccessCtrl1 indirect_access_ctrl1 indirect_access_ctrl1_mut [
         [RW 0..=7; 0] IndirectAddrLow indirect_addr_low,
     ],
 
-    0x7B IndirectData8 indirect_data8 [
+    0x7B IndirectData8 indirect_data8 indirect_data8_mut [
         [R 7; 0] CpuReadStatus cpu_read_status,
         [RW 0..=2; 0] Data data,
     ],
-    0x7C IndirectData7 indirect_data7 [
+    0x7C IndirectData7 indirect_data7 indirect_data7_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x7D IndirectData6 indirect_data6 [
+    0x7D IndirectData6 indirect_data6 indirect_data6_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x7E IndirectData5 indirect_data5 [
+    0x7E IndirectData5 indirect_data5 indirect_data5_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x7F IndirectData4 indirect_data4 [
+    0x7F IndirectData4 indirect_data4 indirect_data4_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x80 IndirectData3 indirect_data3 [
+    0x80 IndirectData3 indirect_data3 indirect_data3_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x81 IndirectData2 indirect_data2 [
+    0x81 IndirectData2 indirect_data2 indirect_data2_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x82 IndirectData1 indirect_data1 [
+    0x82 IndirectData1 indirect_data1 indirect_data1_mut [
         [RW 0..=7; 0] Data data,
     ],
-    0x83 IndirectData0 indirect_data0 [
+    0x83 IndirectData0 indirect_data0 indirect_data0_mut [
         [RW 0..=7; 0] Data data,
     ],
 
-    0x8E Station1MacAddr0 station1_mac_addr0 [
+    0x8E Station1MacAddr0 station1_mac_addr0 station1_mac_addr0_mut [
         [RW 0..=7] Data data,
     ],
-    0x8F Station1MacAddr1 station1_mac_addr1 [
+    0x8F Station1MacAddr1 station1_mac_addr1 station1_mac_addr1_mut [
         [RW 0..=7] Data data,
     ],
-    0x90 Station1MacAddr2 station1_mac_addr2 [
+    0x90 Station1MacAddr2 station1_mac_addr2 station1_mac_addr2_mut [
         [RW 0..=7] Data data,
     ],
-    0x91 Station1MacAddr3 station1_mac_addr3 [
+    0x91 Station1MacAddr3 station1_mac_addr3 station1_mac_addr3_mut [
         [RW 0..=7] Data data,
     ],
-    0x92 Station1MacAddr4 station1_mac_addr4 [
+    0x92 Station1MacAddr4 station1_mac_addr4 station1_mac_addr4_mut [
         [RW 0..=7] Data data,
     ],
-    0x93 Station1MacAddr5 station1_mac_addr5 [
+    0x93 Station1MacAddr5 station1_mac_addr5 station1_mac_addr5_mut [
         [RW 0..=7] Data data,
     ],
 
-    0x94 Station2MacAddr0 station2_mac_addr0 [
+    0x94 Station2MacAddr0 station2_mac_addr0 station2_mac_addr0_mut [
         [RW 0..=7] Data data,
     ],
-    0x95 Station2MacAddr1 station2_mac_addr1 [
+    0x95 Station2MacAddr1 station2_mac_addr1 station2_mac_addr1_mut [
         [RW 0..=7] Data data,
     ],
-    0x96 Station2MacAddr2 station2_mac_addr2 [
+    0x96 Station2MacAddr2 station2_mac_addr2 station2_mac_addr2_mut [
         [RW 0..=7] Data data,
     ],
-    0x97 Station2MacAddr3 station2_mac_addr3 [
+    0x97 Station2MacAddr3 station2_mac_addr3 station2_mac_addr3_mut [
         [RW 0..=7] Data data,
     ],
-    0x98 Station2MacAddr4 station2_mac_addr4 [
+    0x98 Station2MacAddr4 station2_mac_addr4 station2_mac_addr4_mut [
         [RW 0..=7] Data data,
     ],
-    0x99 Station2MacAddr5 station2_mac_addr5 [
+    0x99 Station2MacAddr5 station2_mac_addr5 station2_mac_addr5_mut [
         [RW 0..=7] Data data,
     ],
 
-    // TODO: [0x9A ..= 0xA5] Per-Port Egress Data Rate Limit
+    // Per-Port Egress Data Rate Limit
 
-    0xA6 Mode mode [
+    0x9A Port1Q0EgressRateLimit port1_q0_egress_rate_limit port1_q0_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0x9B Port1Q1EgressRateLimit port1_q1_egress_rate_limit port1_q1_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0x9C Port1Q2EgressRateLimit port1_q2_egress_rate_limit port1_q2_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0x9D Port1Q3EgressRateLimit port1_q3_egress_rate_limit port1_q3_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0x9E Port2Q0EgressRateLimit port2_q0_egress_rate_limit port2_q0_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0x9F Port2Q1EgressRateLimit port2_q1_egress_rate_limit port2_q1_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA0 Port2Q2EgressRateLimit port2_q2_egress_rate_limit port2_q2_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA1 Port2Q3EgressRateLimit port2_q3_egress_rate_limit port2_q3_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA2 Port3Q0EgressRateLimit port3_q0_egress_rate_limit port3_q0_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA3 Port3Q1EgressRateLimit port3_q1_egress_rate_limit port3_q1_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA4 Port3Q2EgressRateLimit port3_q2_egress_rate_limit port3_q2_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+    0xA5 Port3Q3EgressRateLimit port3_q3_egress_rate_limit port3_q3_egress_rate_limit_mut [
+        [RW 0..=6; 0] Limit limit,
+    ],
+
+    // Decoded via `Mode::variant`.
+    0xA6 Mode mode mode_mut [
         [R 0..=7] Data data,
     ],
 
-    0xA7 HighPriorityPacketBufferQ3 high_priority_packet_buffer_q3 [
-        [R 0..=7; 0x45] Data data,
+    0xA7 HighPriorityPacketBufferQ3 high_priority_packet_buffer_q3 high_priority_packet_buffer_q3_mut [
+        [RW 0..=7; 0x45] Data data,
     ],
-    0xA8 HighPriorityPacketBufferQ2 high_priority_packet_buffer_q2 [
-        [R 0..=7; 0x35] Data data,
+    0xA8 HighPriorityPacketBufferQ2 high_priority_packet_buffer_q2 high_priority_packet_buffer_q2_mut [
+        [RW 0..=7; 0x35] Data data,
     ],
-    0xA9 HighPriorityPacketBufferQ1 high_priority_packet_buffer_q1 [
-        [R 0..=7; 0x25] Data data,
+    0xA9 HighPriorityPacketBufferQ1 high_priority_packet_buffer_q1 high_priority_packet_buffer_q1_mut [
+        [RW 0..=7; 0x25] Data data,
     ],
-    0xAA HighPriorityPacketBufferQ0 high_priority_packet_buffer_q0 [
-        [R 0..=7; 0x15] Data data,
+    0xAA HighPriorityPacketBufferQ0 high_priority_packet_buffer_q0 high_priority_packet_buffer_q0_mut [
+        [RW 0..=7; 0x15] Data data,
     ],
 
-    0xAB PmUsageFlowCtrlSelectMode1 pm_usage_flow_ctrl_select_mode_1 [
+    0xAB PmUsageFlowCtrlSelectMode1 pm_usage_flow_ctrl_select_mode_1 pm_usage_flow_ctrl_select_mode_1_mut [
         [R 0..=7] Data data,
     ],
-    0xAC PmUsageFlowCtrlSelectMode2 pm_usage_flow_ctrl_select_mode_2 [
+    0xAC PmUsageFlowCtrlSelectMode2 pm_usage_flow_ctrl_select_mode_2 pm_usage_flow_ctrl_select_mode_2_mut [
         [R 0..=7] Data data,
     ],
-    0xAD PmUsageFlowCtrlSelectMode3 pm_usage_flow_ctrl_select_mode_3 [
+    0xAD PmUsageFlowCtrlSelectMode3 pm_usage_flow_ctrl_select_mode_3 pm_usage_flow_ctrl_select_mode_3_mut [
         [R 0..=7] Data data,
     ],
-    0xAE PmUsageFlowCtrlSelectMode4 pm_usage_flow_ctrl_select_mode_4 [
+    0xAE PmUsageFlowCtrlSelectMode4 pm_usage_flow_ctrl_select_mode_4 pm_usage_flow_ctrl_select_mode_4_mut [
         [R 0..=7] Data data,
     ],
 
-    0xAF Port1TxqSplitForQ3 port1_txq_split_for_q3 [
+    0xAF Port1TxqSplitForQ3 port1_txq_split_for_q3 port1_txq_split_for_q3_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB0 Port1TxqSplitForQ2 port1_txq_split_for_q2 [
+    0xB0 Port1TxqSplitForQ2 port1_txq_split_for_q2 port1_txq_split_for_q2_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB1 Port1TxqSplitForQ1 port1_txq_split_for_q1 [
+    0xB1 Port1TxqSplitForQ1 port1_txq_split_for_q1 port1_txq_split_for_q1_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB2 Port1TxqSplitForQ0 port1_txq_split_for_q0 [
+    0xB2 Port1TxqSplitForQ0 port1_txq_split_for_q0 port1_txq_split_for_q0_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
 
-    0xB3 Port2TxqSplitForQ3 port2_txq_split_for_q3 [
+    0xB3 Port2TxqSplitForQ3 port2_txq_split_for_q3 port2_txq_split_for_q3_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB4 Port2TxqSplitForQ2 port2_txq_split_for_q2 [
+    0xB4 Port2TxqSplitForQ2 port2_txq_split_for_q2 port2_txq_split_for_q2_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB5 Port2TxqSplitForQ1 port2_txq_split_for_q1 [
+    0xB5 Port2TxqSplitForQ1 port2_txq_split_for_q1 port2_txq_split_for_q1_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB6 Port2TxqSplitForQ0 port2_txq_split_for_q0 [
+    0xB6 Port2TxqSplitForQ0 port2_txq_split_for_q0 port2_txq_split_for_q0_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
 
-    0xB7 Port3TxqSplitForQ3 port3_txq_split_for_q3 [
+    0xB7 Port3TxqSplitForQ3 port3_txq_split_for_q3 port3_txq_split_for_q3_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB8 Port3TxqSplitForQ2 port3_txq_split_for_q2 [
+    0xB8 Port3TxqSplitForQ2 port3_txq_split_for_q2 port3_txq_split_for_q2_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xB9 Port3TxqSplitForQ1 port3_txq_split_for_q1 [
+    0xB9 Port3TxqSplitForQ1 port3_txq_split_for_q1 port3_txq_split_for_q1_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
-    0xBA Port3TxqSplitForQ0 port3_txq_split_for_q0 [
+    0xBA Port3TxqSplitForQ0 port3_txq_split_for_q0 port3_txq_split_for_q0_mut [
         [RW 7; 1] PrioritySelect priority_select,
     ],
 
-    0xBB InterruptEnable interrupt_enable [
+    0xBB InterruptEnable interrupt_enable interrupt_enable_mut [
         [RW 0..=7; 0] Reg reg,
     ],
-    0xBC LinkChangeInterrupt link_change_interrupt [
+    0xBC LinkChangeInterrupt link_change_interrupt link_change_interrupt_mut [
         [RW 7; 0] P1P2 p1_p2,
         [RW 2; 0] P3 p3,
         [RW 1; 0] P2 p2,
         [RW 0; 0] P1 p1,
     ],
-    0xBD ForcePauseOff force_pause_off [
-        [RW 0..=7; 0] IterationLimitEnable iteration_limit_enable,
+    0xBD ForcePauseOff force_pause_off force_pause_off_mut [
+        [R 1..=7; 0] Reserved reserved,
+        [RW 0; 0] IterationLimitEnable iteration_limit_enable,
     ],
-    0xC0 FiberSignalThreshold fiber_signal_threshold [
+    0xC0 FiberSignalThreshold fiber_signal_threshold fiber_signal_threshold_mut [
         [RW 7; 0] Port2 port2,
         [RW 6; 0] Port1 port1,
     ],
-    0xC1 InternalLdoCtrl internal_ldo_ctrl [
+    0xC1 InternalLdoCtrl internal_ldo_ctrl internal_ldo_ctrl_mut [
         [RW 6; 0] Disable disable,
     ],
-    0xC2 InsertSrcPvid insert_src_pvid [
+    0xC2 InsertSrcPvid insert_src_pvid insert_src_pvid_mut [
         [RW 5; 0] P1AtP2 p1_at_p2,
         [RW 4; 0] P1AtP3 p1_at_p3,
         [RW 3; 0] P2AtP1 p2_at_p1,
@@ -662,25 +833,178 @@ impl_registers! {
         [RW 1; 0] P3AtP1 p3_at_p1,
         [RW 0; 0] P3AtP2 p3_at_p2,
     ],
-    0xC3 PwrMgmtAndLedMode pwr_mgmt_and_led_mode [
+    0xC3 PwrMgmtAndLedMode pwr_mgmt_and_led_mode pwr_mgmt_and_led_mode_mut [
         [RW 7; 0] CpuIfacePowerDown cpu_iface_power_down,
         [RW 6; 0] SwitchPowerDown switch_power_down,
         [RW 4..=5; 0] LedModeSelection led_mode_selection,
         [RW 3; 0] LedOutputMode led_output_mode,
         [RW 2; 0] PllOff pll_off,
-        [RW 0..=1; 0] PwrMgmtMode pwr_mgmt_mode,
+        [RW 0..=1; 0; enum PwrMgmtMode] PwrMgmtMode pwr_mgmt_mode,
     ],
-    0xC4 SleepMode sleep_mode [
+    0xC4 SleepMode sleep_mode sleep_mode_mut [
         [RW 0..=7; 0x50] Data data,
     ],
-    0xC6 FwdInvalidVidFrameAndHostMode fwd_invalid_vid_frame_and_host_mode [
+    0xC6 FwdInvalidVidFrameAndHostMode fwd_invalid_vid_frame_and_host_mode fwd_invalid_vid_frame_and_host_mode_mut [
         [RW 4..=6; 0] FwdInvalidVidFrame fwd_invalid_vid_frame,
         [RW 3; 0] P3RmiiClockSelection p3_rmii_clock_selection,
         [RW 2; 0] P1RmiiClockSelection p1_rmii_clock_selection,
-        [RW 0..=1] HostIfaceMode host_iface_mode,
+        [RW 0..=1; enum HostIfaceMode] HostIfaceMode host_iface_mode,
     ],
 }
 
+impl_field_enum! {
+    /// The ingress rate limit mode, selected via `Port{1,2,3}Ctrl5::limit_mode`.
+    pub enum LimitMode {
+        /// Limit and count all frames.
+        All = 0b00,
+        /// Limit and count broadcast, multicast and flooded unicast frames.
+        BroadcastMulticastFlooded = 0b01,
+        /// Limit and count broadcast and multicast frames only.
+        BroadcastMulticast = 0b10,
+        /// Limit and count broadcast frames only.
+        Broadcast = 0b11,
+    }
+}
+
+impl_field_enum! {
+    /// The host interface mode, selected via `FwdInvalidVidFrameAndHostMode::host_iface_mode`.
+    ///
+    /// The default is determined by the strap-in pins upon reset.
+    pub enum HostIfaceMode {
+        /// I2C master mode.
+        I2cMaster = 0b00,
+        /// I2C slave mode.
+        I2cSlave = 0b01,
+        /// SPI slave mode.
+        SpiSlave = 0b10,
+        /// SMI mode.
+        Smi = 0b11,
+    }
+}
+
+impl_field_enum! {
+    /// The CPU interface clock, selected via `Gc9::cpu_iface_clk`.
+    ///
+    /// The encoding `0b11` is reserved.
+    pub enum CpuIfaceClk {
+        /// 31.25MHz.
+        Mhz31_25 = 0b00,
+        /// 62.5MHz.
+        Mhz62_5 = 0b01,
+        /// 125MHz.
+        Mhz125 = 0b10,
+    }
+}
+
+impl_field_enum! {
+    /// An egress queue, selected for each 802.1p priority via `Gc10` and `Gc11`.
+    pub enum Queue {
+        /// Queue 0, the lowest priority.
+        Q0 = 0b00,
+        /// Queue 1.
+        Q1 = 0b01,
+        /// Queue 2.
+        Q2 = 0b10,
+        /// Queue 3, the highest priority.
+        Q3 = 0b11,
+    }
+}
+
+impl_field_enum! {
+    /// The power management mode, selected via `PwrMgmtAndLedMode::pwr_mgmt_mode`.
+    pub enum PwrMgmtMode {
+        /// Normal operation.
+        Normal = 0b00,
+        /// Energy detect mode, where the switch powers down when no cable is connected.
+        EnergyDetect = 0b01,
+        /// Soft power down mode.
+        SoftPowerDown = 0b10,
+        /// Power saving mode.
+        PowerSaving = 0b11,
+    }
+}
+
+impl_field_enum! {
+    /// The output pad drive strength, selected via `Gc12::drive_strength`.
+    pub enum DriveStrength {
+        /// 4mA.
+        Ma4 = 0,
+        /// 8mA.
+        Ma8 = 1,
+    }
+}
+
+impl Queue {
+    /// All queues, indexed by their 2-bit encoding.
+    pub const ALL: [Self; 4] = [Self::Q0, Self::Q1, Self::Q2, Self::Q3];
+}
+
+impl<'a, 'b> pwr_mgmt_and_led_mode::PwrMgmtMode<&'a mut W<&'b mut PwrMgmtAndLedMode>> {
+    /// Select normal operation.
+    pub fn normal(self) -> &'a mut W<&'b mut PwrMgmtAndLedMode> {
+        self.variant(PwrMgmtMode::Normal)
+    }
+
+    /// Select energy detect mode.
+    pub fn energy_detect(self) -> &'a mut W<&'b mut PwrMgmtAndLedMode> {
+        self.variant(PwrMgmtMode::EnergyDetect)
+    }
+
+    /// Select soft power down mode.
+    pub fn soft_power_down(self) -> &'a mut W<&'b mut PwrMgmtAndLedMode> {
+        self.variant(PwrMgmtMode::SoftPowerDown)
+    }
+
+    /// Select power saving mode.
+    pub fn power_saving(self) -> &'a mut W<&'b mut PwrMgmtAndLedMode> {
+        self.variant(PwrMgmtMode::PowerSaving)
+    }
+}
+
+impl gc12::DriveStrength<&R<&Gc12>> {
+    /// Value of the field as a typed variant.
+    pub fn variant(&self) -> DriveStrength {
+        match self.bit() {
+            false => DriveStrength::Ma4,
+            true => DriveStrength::Ma8,
+        }
+    }
+}
+
+impl<'a, 'b> gc12::DriveStrength<&'a mut W<&'b mut Gc12>> {
+    /// Set the field to the given variant.
+    pub fn variant(self, variant: DriveStrength) -> &'a mut W<&'b mut Gc12> {
+        self.bit(variant == DriveStrength::Ma8)
+    }
+}
+
+/// Implements typed `PortSet` access for the `port_vlan_membership` field of each port's `Ctrl1`.
+macro_rules! impl_port_vlan_membership {
+    ($($reg:ident $Reg:ident,)*) => {
+        $(
+            impl<'a, 'b> $reg::PortVlanMembership<&'a R<&'b $Reg>> {
+                /// The set of ports to which this port may forward frames.
+                pub fn ports(&self) -> PortSet {
+                    PortSet::from(self.bits())
+                }
+            }
+
+            impl<'a, 'b> $reg::PortVlanMembership<&'a mut W<&'b mut $Reg>> {
+                /// Set the ports to which this port may forward frames.
+                pub fn ports(self, ports: PortSet) -> &'a mut W<&'b mut $Reg> {
+                    self.bits(ports.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_port_vlan_membership! {
+    port1_ctrl1 Port1Ctrl1,
+    port2_ctrl1 Port2Ctrl1,
+    port3_ctrl1 Port3Ctrl1,
+}
+
 impl<T> Smi<T> {
     /// Access a particular register associated with this PHY.
     pub fn reg<R>(&mut self) -> Reg<T, R> {
@@ -699,6 +1023,18 @@ impl<T> Smi<T> {
         Ok(State::from_addr_and_data(addr, bits))
     }
 
+    /// Read the register with the given raw address.
+    ///
+    /// Returns `Error::InvalidAddress` without performing a read if there is no register at
+    /// the given address.
+    pub fn read_raw<E>(&mut self, addr: u8) -> Result<State, crate::Error<E>>
+    where
+        T: Read<Error = E>,
+    {
+        let addr: Address = core::convert::TryFrom::try_from(addr)?;
+        self.read(addr).map_err(crate::Error::Interface)
+    }
+
     /// Write the given register state to the register with the associated address.
     pub fn write(&mut self, state: State) -> Result<(), T::Error>
     where
@@ -706,6 +1042,286 @@ impl<T> Smi<T> {
     {
         self.0.write(state.addr().into(), state.into())
     }
+
+    /// Read the register of type `R`, e.g. `read_reg::<Gc1>()`.
+    ///
+    /// Equivalent to `reg::<R>().read()`.
+    pub fn read_reg<R>(&mut self) -> Result<R, T::Error>
+    where
+        T: Read,
+        R: Register,
+    {
+        let bits = self.0.read(R::ADDRESS.into())?;
+        Ok(R::from(bits))
+    }
+
+    /// Write the given value to the register of type `R`.
+    ///
+    /// The value is written as is, including any read-only fields.
+    pub fn write_reg<R>(&mut self, value: R) -> Result<(), T::Error>
+    where
+        T: Write,
+        R: Register,
+    {
+        self.0.write(R::ADDRESS.into(), value.into())
+    }
+
+    /// Read the register at the given address, modify its state via `f` and write the result.
+    ///
+    /// This is useful for generic tooling that only knows the register address at runtime.
+    ///
+    /// Returns `Error::AddressChanged` without writing if `f` changes the variant of the `State`,
+    /// as the result would otherwise be written to a different register.
+    pub fn modify_state<F, E>(&mut self, addr: Address, f: F) -> Result<(), crate::Error<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        F: FnOnce(&mut State),
+    {
+        let mut state = self.read(addr).map_err(crate::Error::Interface)?;
+        f(&mut state);
+        if state.addr() != addr {
+            return Err(crate::Error::AddressChanged);
+        }
+        self.write(state).map_err(crate::Error::Interface)
+    }
+
+    /// Trigger a software reset of the switch via `Reset::software`.
+    pub fn software_reset<E>(&mut self) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reset().modify(|w| w.software().set_bit())
+    }
+
+    /// Start or stop the switch via `ChipId1::start_switch`.
+    ///
+    /// Only the `start_switch` bit is modified, so the read-only chip and revision IDs are
+    /// written back exactly as read. The bit is not self-clearing: the switch remains stopped
+    /// until it is started again.
+    pub fn set_start_switch<E>(&mut self, start: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.chip_id1().modify(|w| w.start_switch().bit(start))
+    }
+
+    /// The MIIM addresses of the PHYs of ports 1 and 2, as configured via `Gc13::phy_addr`.
+    pub fn phy_addrs(&mut self) -> Result<[u8; 2], T::Error>
+    where
+        T: Read,
+    {
+        let base = self.gc13().read()?.read().phy_addr().bits();
+        Ok(crate::miim::phy_addrs(base))
+    }
+
+    /// Set the base MIIM address of the PHYs via `Gc13::phy_addr`.
+    ///
+    /// Port 1's PHY responds at `base` and port 2's at `base + 1`. Only the lower 5 bits of `base`
+    /// are used.
+    pub fn set_phy_base_addr<E>(&mut self, base: u8) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.gc13().modify(|w| w.phy_addr().bits(base & 0x1F))
+    }
+
+    /// The ports to which frames with an unknown destination address are forwarded, or `None` if
+    /// such frames are flooded as usual.
+    ///
+    /// Reads `Gc12::unknown_packet_default_port_enable` and `unknown_packet_default_port`.
+    pub fn unknown_packet_default_port(&mut self) -> Result<Option<PortSet>, T::Error>
+    where
+        T: Read,
+    {
+        let gc12 = self.gc12().read()?;
+        let r = gc12.read();
+        if r.unknown_packet_default_port_enable().bit_is_clear() {
+            return Ok(None);
+        }
+        Ok(Some(PortSet::from(r.unknown_packet_default_port().bits())))
+    }
+
+    /// Forward frames with an unknown destination address to the given ports, or flood them as
+    /// usual if `None`.
+    ///
+    /// The enable bit and port bitmap within `Gc12` are written together. The port bitmap is left
+    /// untouched when disabling.
+    pub fn set_unknown_packet_default_port<E>(&mut self, ports: Option<PortSet>) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.gc12().modify(|w| match ports {
+            None => w.unknown_packet_default_port_enable().clear_bit(),
+            Some(ports) => w
+                .unknown_packet_default_port_enable()
+                .set_bit()
+                .unknown_packet_default_port()
+                .bits(ports.into()),
+        })
+    }
+
+    /// Read every register from the device, producing a snapshot of its full state.
+    pub fn read_map(&mut self) -> Result<Map, T::Error>
+    where
+        T: Read,
+    {
+        let mut map = Map::default();
+        for &addr in Address::ALL {
+            map.set_state(self.read(addr)?);
+        }
+        Ok(map)
+    }
+
+    /// Write the state of every writable register within the given map, in address order.
+    ///
+    /// Read-only registers and those with side effects on write are skipped. See
+    /// `Address::is_bulk_writable`. Self-clearing trigger bits are always written as `0`.
+    pub fn write_map(&mut self, map: &Map) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        for (addr, state) in map.iter() {
+            if addr.is_bulk_writable() {
+                self.write_bulk(state)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the power-on default value to every writable register, in address order.
+    ///
+    /// This restores the default configuration without a hardware or software reset. Registers
+    /// with side effects on write are skipped (see `Address::is_bulk_writable`). Note that this
+    /// includes `ChipId1::start_switch`, so a stopped switch is started.
+    pub fn reset_all(&mut self) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        for addr in Address::writable().filter(|addr| addr.is_bulk_writable()) {
+            self.write_bulk(&State::from_addr_default(addr))?;
+        }
+        Ok(())
+    }
+
+    /// Write the state from `to` of each register whose writable bits differ between `from` and
+    /// `to`, in address order.
+    ///
+    /// Registers that only differ in read-only bits are skipped, as are those with side effects
+    /// on write (see `Address::is_bulk_writable`). Self-clearing trigger bits are always written
+    /// as `0`. Returns the number of registers written.
+    pub fn write_diff(&mut self, from: &Map, to: &Map) -> Result<usize, T::Error>
+    where
+        T: Write,
+    {
+        let mut count = 0;
+        for (addr, a, b) in from.diff(to) {
+            if addr.is_bulk_writable() && !a.eq_writable(b) {
+                self.write_bulk(b)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Write the given state as part of a bulk write, with its self-clearing bits masked out.
+    fn write_bulk(&mut self, state: &State) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        let addr = state.addr();
+        let data: u8 = (*state).into();
+        self.0.write(addr.into(), data & !addr.self_clearing_mask())
+    }
+}
+
+impl<T> Smi<T> {
+    /// Read the 11-bit broadcast storm protection rate from `Gc4` (bits `10:8`) and `Gc5` (bits
+    /// `7:0`).
+    ///
+    /// The rate is the number of 64-byte blocks of packet data permitted on an input port within a
+    /// preset period of 67ms for 100BT or 500ms for 10BT.
+    pub fn broadcast_storm_rate(&mut self) -> Result<u16, T::Error>
+    where
+        T: Read,
+    {
+        let gc4 = self.gc4().read()?;
+        let gc5 = self.gc5().read()?;
+        let high = gc4.read().broadcast_storm_protection_rate_high().bits();
+        let low = gc5.read().broadcast_storm_protection_rate_low().bits();
+        Ok(u16::from_be_bytes([high, low]))
+    }
+
+    /// Write the 11-bit broadcast storm protection rate to `Gc4` (bits `10:8`) and `Gc5` (bits
+    /// `7:0`).
+    ///
+    /// Rates exceeding `MAX_BROADCAST_STORM_RATE` saturate to the maximum.
+    pub fn set_broadcast_storm_rate<E>(&mut self, rate: u16) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let rate = core::cmp::min(rate, MAX_BROADCAST_STORM_RATE);
+        let [high, low] = rate.to_be_bytes();
+        self.gc4()
+            .modify(|w| w.broadcast_storm_protection_rate_high().bits(high))?;
+        self.gc5()
+            .write(|w| w.broadcast_storm_protection_rate_low().bits(low))
+    }
+
+    /// Read the egress queue assigned to each 802.1p priority from `Gc10` and `Gc11`.
+    ///
+    /// Element `n` of the returned array holds the queue for priority `n`.
+    pub fn priority_queue_map(&mut self) -> Result<[Queue; 8], T::Error>
+    where
+        T: Read,
+    {
+        let gc10 = self.gc10().read()?;
+        let gc11 = self.gc11().read()?;
+        let (r10, r11) = (gc10.read(), gc11.read());
+        let bits = [
+            r10.tag_0x0().bits(),
+            r10.tag_0x1().bits(),
+            r10.tag_0x2().bits(),
+            r10.tag_0x3().bits(),
+            r11.tag_0x4().bits(),
+            r11.tag_0x5().bits(),
+            r11.tag_0x6().bits(),
+            r11.tag_0x7().bits(),
+        ];
+        let mut map = [Queue::Q0; 8];
+        for (queue, &bits) in map.iter_mut().zip(bits.iter()) {
+            *queue = Queue::ALL[usize::from(bits & 0b11)];
+        }
+        Ok(map)
+    }
+
+    /// Write the egress queue assigned to each 802.1p priority to `Gc10` and `Gc11`.
+    ///
+    /// Element `n` of the given array holds the queue for priority `n`.
+    pub fn set_priority_queue_map(&mut self, map: [Queue; 8]) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.gc10().write(|w| {
+            w.tag_0x0()
+                .variant(map[0])
+                .tag_0x1()
+                .variant(map[1])
+                .tag_0x2()
+                .variant(map[2])
+                .tag_0x3()
+                .variant(map[3])
+        })?;
+        self.gc11().write(|w| {
+            w.tag_0x4()
+                .variant(map[4])
+                .tag_0x5()
+                .variant(map[5])
+                .tag_0x6()
+                .variant(map[6])
+                .tag_0x7()
+                .variant(map[7])
+        })
+    }
 }
 
 impl<'smi, T, R> Reg<'smi, T, R>
@@ -722,12 +1338,24 @@ where
     }
 
     /// Write to the register `R`, initialised with a default state.
+    ///
+    /// Note that every field left untouched by `write` is written with its default value rather
+    /// than its current value, including read-only and reserved fields. Use `modify` to preserve
+    /// the current value of untouched fields.
     pub fn write<F>(&mut self, write: F) -> Result<(), T::Error>
     where
         T: Write,
         F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
     {
-        let mut reg = R::default();
+        self.write_from(R::default(), write)
+    }
+
+    /// Write to the register `R`, initialised with the given state.
+    pub fn write_from<F>(&mut self, mut reg: R, write: F) -> Result<(), T::Error>
+    where
+        T: Write,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
         write(&mut W(&mut reg));
         self.smi.0.write(R::ADDRESS.into(), reg.into())
     }
@@ -745,6 +1373,49 @@ where
         modify(&mut W(&mut reg));
         self.smi.0.write(R::ADDRESS.into(), reg.into())
     }
+
+    /// Modify the register `R`, only writing the result if it differs from the value read.
+    ///
+    /// Returns whether or not the write occurred.
+    pub fn modify_if_changed<F, E>(&mut self, modify: F) -> Result<bool, E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        R: Copy + PartialEq,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let read: R = self.read()?;
+        let mut reg = read;
+        modify(&mut W(&mut reg));
+        if reg == read {
+            return Ok(false);
+        }
+        self.smi.0.write(R::ADDRESS.into(), reg.into())?;
+        Ok(true)
+    }
+
+    /// Modify the register `R` and read it back to verify that the write took effect.
+    ///
+    /// Only the bits within `Register::WRITABLE_MASK` and outside of
+    /// `Register::SELF_CLEARING_MASK` are compared, so neither read-only fields nor self-clearing
+    /// bits such as `Gc0::flush_dynamic_mac_table` cause a mismatch.
+    pub fn modify_verified<F, E>(&mut self, modify: F) -> Result<(), crate::VerifyError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg: R = self.read().map_err(crate::VerifyError::Interface)?;
+        modify(&mut W(&mut reg));
+        let written: u8 = reg.into();
+        self.smi
+            .0
+            .write(R::ADDRESS.into(), written)
+            .map_err(crate::VerifyError::Interface)?;
+        let read: u8 = self.read().map_err(crate::VerifyError::Interface)?.into();
+        if (read ^ written) & R::WRITABLE_MASK & !R::SELF_CLEARING_MASK != 0 {
+            return Err(crate::VerifyError::Mismatch);
+        }
+        Ok(())
+    }
 }
 
 impl<T> Read for T
@@ -0,0 +1,78 @@
+//! Asynchronous access to the SMI interface, enabled via the `async` feature.
+
+use super::{Address, Reg, Register, Smi, State, W};
+use core::future::Future;
+
+/// A trait for asynchronously reading from the KSZ8863's SMI interface.
+pub trait AsyncRead {
+    /// Errors that might occur on the SMI interface.
+    type Error;
+    /// Read the data from the given register address.
+    fn read(&mut self, reg_addr: u8) -> impl Future<Output = Result<u8, Self::Error>>;
+}
+
+/// A trait for asynchronously writing to the KSZ8863's SMI interface.
+pub trait AsyncWrite {
+    /// Errors that might occur on the SMI interface.
+    type Error;
+    /// Write to the register at the given address.
+    fn write(&mut self, reg_addr: u8, data: u8) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+impl<T> Smi<T> {
+    /// Asynchronously read the register with the given address.
+    pub async fn read_async(&mut self, addr: Address) -> Result<State, T::Error>
+    where
+        T: AsyncRead,
+    {
+        let bits = self.0.read(addr.into()).await?;
+        Ok(State::from_addr_and_data(addr, bits))
+    }
+
+    /// Asynchronously write the given register state to the register with the associated address.
+    pub async fn write_async(&mut self, state: State) -> Result<(), T::Error>
+    where
+        T: AsyncWrite,
+    {
+        self.0.write(state.addr().into(), state.into()).await
+    }
+}
+
+impl<'smi, T, R> Reg<'smi, T, R>
+where
+    R: Register,
+{
+    /// Asynchronously read the value from register `R` via SMI.
+    pub async fn read_async(&mut self) -> Result<R, T::Error>
+    where
+        T: AsyncRead,
+    {
+        let bits = self.smi.0.read(R::ADDRESS.into()).await?;
+        Ok(R::from(bits))
+    }
+
+    /// Asynchronously write to the register `R`, initialised with a default state.
+    pub async fn write_async<F>(&mut self, write: F) -> Result<(), T::Error>
+    where
+        T: AsyncWrite,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg = R::default();
+        write(&mut W(&mut reg));
+        self.smi.0.write(R::ADDRESS.into(), reg.into()).await
+    }
+
+    /// Asynchronously modify the register `R`.
+    ///
+    /// This first reads the value from the register, delivers it to the user via the `modify`
+    /// function, and then writes the result.
+    pub async fn modify_async<F, E>(&mut self, modify: F) -> Result<(), E>
+    where
+        T: AsyncRead<Error = E> + AsyncWrite<Error = E>,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg: R = self.read_async().await?;
+        modify(&mut W(&mut reg));
+        self.smi.0.write(R::ADDRESS.into(), reg.into()).await
+    }
+}
@@ -0,0 +1,63 @@
+//! Per-queue access to the high priority packet buffer registers.
+
+use super::{Queue, Read, Smi, Write};
+
+impl<T> Smi<T> {
+    /// Read the number of high priority packet buffers reserved for the given queue.
+    ///
+    /// Reads the matching register of `HighPriorityPacketBufferQ0..=Q3`. Note that the registers
+    /// are laid out in reverse queue order, from `Q3` at `0xA7` to `Q0` at `0xAA`.
+    pub fn high_priority_buffer(&mut self, queue: Queue) -> Result<u8, T::Error>
+    where
+        T: Read,
+    {
+        let bits = match queue {
+            Queue::Q0 => self
+                .high_priority_packet_buffer_q0()
+                .read()?
+                .read()
+                .data()
+                .bits(),
+            Queue::Q1 => self
+                .high_priority_packet_buffer_q1()
+                .read()?
+                .read()
+                .data()
+                .bits(),
+            Queue::Q2 => self
+                .high_priority_packet_buffer_q2()
+                .read()?
+                .read()
+                .data()
+                .bits(),
+            Queue::Q3 => self
+                .high_priority_packet_buffer_q3()
+                .read()?
+                .read()
+                .data()
+                .bits(),
+        };
+        Ok(bits)
+    }
+
+    /// Write the number of high priority packet buffers reserved for the given queue.
+    pub fn set_high_priority_buffer(&mut self, queue: Queue, buffers: u8) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        match queue {
+            Queue::Q0 => self
+                .high_priority_packet_buffer_q0()
+                .write(|w| w.data().bits(buffers)),
+            Queue::Q1 => self
+                .high_priority_packet_buffer_q1()
+                .write(|w| w.data().bits(buffers)),
+            Queue::Q2 => self
+                .high_priority_packet_buffer_q2()
+                .write(|w| w.data().bits(buffers)),
+            Queue::Q3 => self
+                .high_priority_packet_buffer_q3()
+                .write(|w| w.data().bits(buffers)),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Identification of the KSZ8863 variant via the `Mode` register.
+
+use super::Mode;
+use core::convert::TryFrom;
+
+/// The variant of the KSZ8863, as reported by the read-only `Mode` register.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum ChipVariant {
+    /// KSZ8863MLL, with an MII interface on port 3. Reports `0x43`.
+    Mll,
+    /// KSZ8863FLL, with an MII interface on port 3 and 100BASE-FX fiber support. Reports `0x53`.
+    Fll,
+    /// KSZ8863RLL, with an RMII interface on port 3. Reports `0x07`.
+    Rll,
+}
+
+impl ChipVariant {
+    /// Whether or not port 3 uses an RMII rather than an MII interface.
+    pub fn is_rmii(self) -> bool {
+        self == ChipVariant::Rll
+    }
+
+    /// Whether or not the variant supports 100BASE-FX fiber operation.
+    pub fn supports_fiber(self) -> bool {
+        self == ChipVariant::Fll
+    }
+}
+
+impl TryFrom<u8> for ChipVariant {
+    type Error = crate::InvalidValue;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x43 => Ok(ChipVariant::Mll),
+            0x53 => Ok(ChipVariant::Fll),
+            0x07 => Ok(ChipVariant::Rll),
+            _ => Err(crate::InvalidValue),
+        }
+    }
+}
+
+impl From<ChipVariant> for u8 {
+    fn from(variant: ChipVariant) -> Self {
+        match variant {
+            ChipVariant::Mll => 0x43,
+            ChipVariant::Fll => 0x53,
+            ChipVariant::Rll => 0x07,
+        }
+    }
+}
+
+impl Mode {
+    /// Decode the chip variant reported by the register.
+    ///
+    /// Returns `InvalidValue` if the register holds an unrecognised value.
+    pub fn variant(&self) -> Result<ChipVariant, crate::InvalidValue> {
+        ChipVariant::try_from(self.read().data().bits())
+    }
+}
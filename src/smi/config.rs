@@ -0,0 +1,161 @@
+//! A declarative builder for the initial configuration of the switch.
+
+use super::{
+    Address, FlowControlConfig, Gc1, Gc3, Map, Port, Port1Ctrl1, Port1Ctrl2, Port1Ctrl3,
+    Port1Ctrl4, PortId, PortSet, Register, Smi, State, VlanTag, Write, W,
+};
+
+/// Accumulates the desired state of the switch registers before writing them via `apply`.
+///
+/// The builder starts from `Map::default()`. Only the registers touched by the builder methods
+/// are written by `apply`. Where methods touch the same field, the last call takes precedence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    map: Map,
+    touched: [bool; Map::LEN],
+}
+
+/// The configuration of a single port, applied via `Config::port`.
+///
+/// The `Default` implementation matches the register defaults.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PortConfig {
+    /// Enable transmission and reception via `Ctrl2::transmit` and `Ctrl2::receive`.
+    pub enabled: bool,
+    /// Enable address learning, i.e. the inverse of `Ctrl2::learning_disable`.
+    pub learning: bool,
+    /// Force flow control regardless of the auto-negotiation result via
+    /// `Ctrl2::force_flow_control`.
+    pub force_flow_control: bool,
+    /// The ports to which this port may forward frames via `Ctrl1::port_vlan_membership`.
+    pub vlan_membership: PortSet,
+    /// The port's default tag, held by `Ctrl3` and `Ctrl4`.
+    pub default_tag: VlanTag,
+}
+
+impl Config {
+    /// Begin a new configuration, starting from `Map::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable 802.1Q VLAN mode via `Gc3::vlan`.
+    pub fn vlan(mut self, enable: bool) -> Self {
+        self.map.reg_mut::<Gc3>().write().vlan().bit(enable);
+        self.touch(Address::Gc3);
+        self
+    }
+
+    /// Enable or disable the tail tag on port 3 via `Gc1::port3_tail_tag`.
+    pub fn tail_tag(mut self, enable: bool) -> Self {
+        self.map
+            .reg_mut::<Gc1>()
+            .write()
+            .port3_tail_tag()
+            .bit(enable);
+        self.touch(Address::Gc1);
+        self
+    }
+
+    /// Configure flow control via `Gc1` and the `Ctrl2` register of each port.
+    pub fn flow_control(mut self, config: FlowControlConfig) -> Self {
+        self.map
+            .reg_mut::<Gc1>()
+            .write()
+            .tx_flow_control()
+            .bit(config.global_tx);
+        self.map
+            .reg_mut::<Gc1>()
+            .write()
+            .rx_flow_control()
+            .bit(config.global_rx);
+        self.touch(Address::Gc1);
+        for (&id, &force) in PortId::ALL.iter().zip(config.port_force.iter()) {
+            self.modify_port::<Port1Ctrl2, _>(id, |w| w.force_flow_control().bit(force));
+        }
+        self
+    }
+
+    /// Configure the given port via its `Ctrl1`, `Ctrl2`, `Ctrl3` and `Ctrl4` registers.
+    pub fn port(mut self, id: PortId, config: PortConfig) -> Self {
+        let [tag_high, tag_low] = u16::from(config.default_tag).to_be_bytes();
+        self.modify_port::<Port1Ctrl1, _>(id, |w| {
+            w.port_vlan_membership().ports(config.vlan_membership)
+        });
+        self.modify_port::<Port1Ctrl2, _>(id, |w| {
+            w.transmit()
+                .bit(config.enabled)
+                .receive()
+                .bit(config.enabled)
+                .learning_disable()
+                .bit(!config.learning)
+                .force_flow_control()
+                .bit(config.force_flow_control)
+        });
+        self.modify_port::<Port1Ctrl3, _>(id, |w| w.default_tag_15_8().bits(tag_high));
+        self.modify_port::<Port1Ctrl4, _>(id, |w| w.default_tag_7_0().bits(tag_low));
+        self
+    }
+
+    /// The accumulated register state.
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    /// Write each register touched by the builder to the device, in address order.
+    pub fn apply<T>(&self, smi: &mut Smi<T>) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        for &addr in Address::ALL {
+            if self.touched[addr.index()] {
+                smi.write(*self.map.state(addr))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Mark the register at the given address to be written by `apply`.
+    fn touch(&mut self, addr: Address) {
+        self.touched[addr.index()] = true;
+    }
+
+    /// Modify the given port's equivalent of the port 1 register `R`.
+    fn modify_port<R, F>(&mut self, id: PortId, modify: F)
+    where
+        R: Register,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let addr = u8::from(R::ADDRESS) + Port::<()>::ADDR_STRIDE * id.index() as u8;
+        if let Ok(addr) = core::convert::TryFrom::try_from(addr) {
+            let mut reg = R::from((*self.map.state(addr)).into());
+            modify(&mut W(&mut reg));
+            self.map
+                .set_state(State::from_addr_and_data(addr, reg.into()));
+            self.touch(addr);
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            map: Map::default(),
+            touched: [false; Map::LEN],
+        }
+    }
+}
+
+impl Default for PortConfig {
+    fn default() -> Self {
+        PortConfig {
+            enabled: true,
+            learning: true,
+            force_flow_control: false,
+            vlan_membership: PortSet::ALL,
+            default_tag: VlanTag::from(0x0001),
+        }
+    }
+}
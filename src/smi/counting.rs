@@ -0,0 +1,73 @@
+//! An instrumentation wrapper that counts SMI transactions.
+
+use super::{Read, Write};
+
+/// Wraps an `smi::Read` and/or `smi::Write` implementation, counting each read and write.
+///
+/// Useful for verifying that higher-level helpers (e.g. `modify_if_changed`) reduce I/O.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Counting<T> {
+    inner: T,
+    reads: usize,
+    writes: usize,
+}
+
+impl<T> Counting<T> {
+    /// Wrap the given interface with both counts starting at `0`.
+    pub fn new(inner: T) -> Self {
+        Counting {
+            inner,
+            reads: 0,
+            writes: 0,
+        }
+    }
+
+    /// The number of reads performed since creation or the last `reset`.
+    pub fn reads(&self) -> usize {
+        self.reads
+    }
+
+    /// The number of writes performed since creation or the last `reset`.
+    pub fn writes(&self) -> usize {
+        self.writes
+    }
+
+    /// Reset both counts to `0`.
+    pub fn reset(&mut self) {
+        self.reads = 0;
+        self.writes = 0;
+    }
+
+    /// Access the wrapped interface.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped interface.
+    ///
+    /// Accesses made directly via the wrapped interface are not counted.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner interface.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for Counting<T> {
+    type Error = T::Error;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.reads += 1;
+        self.inner.read(reg_addr)
+    }
+}
+
+impl<T: Write> Write for Counting<T> {
+    type Error = T::Error;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.writes += 1;
+        self.inner.write(reg_addr, data)
+    }
+}
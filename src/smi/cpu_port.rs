@@ -0,0 +1,99 @@
+//! Configuration of port 3, the MII/RMII port typically connected to the host CPU.
+
+use super::{Read, Smi, Write};
+
+/// The role taken by port 3's MII interface, as held by `Port3Ctrl5::port3_mii_mode_selection`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum MiiMode {
+    /// Port 3 acts as a MAC, for connection to an external PHY.
+    Mac,
+    /// Port 3 acts as a PHY, for connection directly to a host MAC.
+    Phy,
+}
+
+/// The source of port 3's RMII reference clock, as held by
+/// `FwdInvalidVidFrameAndHostMode::p3_rmii_clock_selection`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum RmiiClock {
+    /// The 50MHz reference clock is supplied externally via `REFCLKI_3`.
+    External,
+    /// The 50MHz reference clock is generated internally.
+    Internal,
+}
+
+/// The configuration of port 3's MII/RMII interface.
+///
+/// The fields are spread across the following registers:
+///
+/// | Field           | Register                        | Register field             |
+/// | --------------- | ------------------------------- | -------------------------- |
+/// | `mii_mode`      | `Port3Ctrl5`                    | `port3_mii_mode_selection` |
+/// | `rmii_clock`    | `FwdInvalidVidFrameAndHostMode` | `p3_rmii_clock_selection`  |
+/// | `refclk_invert` | `Port3Q0IngressRateLimit`       | `rmii_refclk_invert`       |
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct CpuPortConfig {
+    /// Whether port 3 acts as a MAC or a PHY.
+    pub mii_mode: MiiMode,
+    /// The source of the RMII reference clock.
+    pub rmii_clock: RmiiClock,
+    /// Invert the RMII reference clock.
+    pub refclk_invert: bool,
+}
+
+impl Default for CpuPortConfig {
+    fn default() -> Self {
+        CpuPortConfig {
+            mii_mode: MiiMode::Mac,
+            rmii_clock: RmiiClock::External,
+            refclk_invert: false,
+        }
+    }
+}
+
+impl<T> Smi<T> {
+    /// Read the port 3 MII/RMII configuration.
+    pub fn cpu_port_config(&mut self) -> Result<CpuPortConfig, T::Error>
+    where
+        T: Read,
+    {
+        let ctrl5 = self.port3_ctrl5().read()?;
+        let host = self.fwd_invalid_vid_frame_and_host_mode().read()?;
+        let q0 = self.port3_q0_ingress_rate_limit().read()?;
+        let mii_mode = match ctrl5.read().port3_mii_mode_selection().bit() {
+            false => MiiMode::Mac,
+            true => MiiMode::Phy,
+        };
+        let rmii_clock = match host.read().p3_rmii_clock_selection().bit() {
+            false => RmiiClock::External,
+            true => RmiiClock::Internal,
+        };
+        Ok(CpuPortConfig {
+            mii_mode,
+            rmii_clock,
+            refclk_invert: q0.read().rmii_refclk_invert().bit(),
+        })
+    }
+
+    /// Write the port 3 MII/RMII configuration.
+    ///
+    /// All other fields of the affected registers are preserved.
+    pub fn set_cpu_port_config<E>(&mut self, config: CpuPortConfig) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let phy_mode = config.mii_mode == MiiMode::Phy;
+        let internal_clock = config.rmii_clock == RmiiClock::Internal;
+        self.port3_ctrl5()
+            .modify(|w| w.port3_mii_mode_selection().bit(phy_mode))?;
+        self.fwd_invalid_vid_frame_and_host_mode()
+            .modify(|w| w.p3_rmii_clock_selection().bit(internal_clock))?;
+        self.port3_q0_ingress_rate_limit()
+            .modify(|w| w.rmii_refclk_invert().bit(config.refclk_invert))
+    }
+}
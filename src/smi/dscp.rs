@@ -0,0 +1,61 @@
+//! Access to the DSCP-to-priority table held by `TosPriorityCtrl0..=TosPriorityCtrl15`.
+//!
+//! Each of the 64 DSCP values maps to a 2-bit priority, packed four per register starting with
+//! DSCP `0` in bits `1:0` of `TosPriorityCtrl0`.
+
+use super::{Address, Read, Smi, Write};
+
+/// The maximum DSCP value, as DSCP values are 6 bits wide.
+pub const MAX_DSCP: u8 = 63;
+
+/// The maximum priority that may be assigned to a DSCP value, as priorities are 2 bits wide.
+pub const MAX_DSCP_PRIORITY: u8 = 0b11;
+
+/// Errors that may occur while accessing the DSCP-to-priority table.
+#[derive(Debug)]
+pub enum DscpError<E> {
+    /// An error occurred on the SMI interface.
+    Interface(E),
+    /// The DSCP value exceeds `MAX_DSCP`.
+    InvalidDscp,
+    /// The priority exceeds `MAX_DSCP_PRIORITY`.
+    InvalidPriority,
+}
+
+impl<T> Smi<T> {
+    /// Read the priority assigned to the given DSCP value.
+    pub fn dscp_priority<E>(&mut self, dscp: u8) -> Result<u8, DscpError<E>>
+    where
+        T: Read<Error = E>,
+    {
+        let (addr, shift) = dscp_location::<E>(dscp)?;
+        let bits = self.0.read(addr).map_err(DscpError::Interface)?;
+        Ok((bits >> shift) & MAX_DSCP_PRIORITY)
+    }
+
+    /// Assign the given priority to the given DSCP value.
+    ///
+    /// Only the register holding the given DSCP value is modified.
+    pub fn set_dscp_priority<E>(&mut self, dscp: u8, priority: u8) -> Result<(), DscpError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        if priority > MAX_DSCP_PRIORITY {
+            return Err(DscpError::InvalidPriority);
+        }
+        let (addr, shift) = dscp_location::<E>(dscp)?;
+        let bits = self.0.read(addr).map_err(DscpError::Interface)?;
+        let bits = (bits & !(MAX_DSCP_PRIORITY << shift)) | (priority << shift);
+        self.0.write(addr, bits).map_err(DscpError::Interface)
+    }
+}
+
+/// The raw register address and bit offset of the priority for the given DSCP value.
+fn dscp_location<E>(dscp: u8) -> Result<(u8, u8), DscpError<E>> {
+    if dscp > MAX_DSCP {
+        return Err(DscpError::InvalidDscp);
+    }
+    let addr = u8::from(Address::TosPriorityCtrl0) + dscp / 4;
+    let shift = (dscp % 4) * 2;
+    Ok((addr, shift))
+}
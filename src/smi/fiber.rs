@@ -0,0 +1,76 @@
+//! Configuration of ports 1 and 2 for 100BASE-FX fiber operation.
+//!
+//! Fiber media is selected via the strap-in pins, but a fiber link also relies on:
+//!
+//! - `FiberSignalThreshold`, which selects the fiber signal detect threshold for each port.
+//! - `Ctrl12`, as auto-negotiation is not supported over fiber and the link must be forced to
+//!   100Mbps.
+//! - `Ctrl13::disable_far_end_fault`, as far-end fault detection only applies to fiber.
+
+use super::{PortError, PortId, Read, Smi, Write};
+use crate::link::{Duplex, Speed};
+
+/// The fiber signal detect threshold, as held by `FiberSignalThreshold::port1` and
+/// `FiberSignalThreshold::port2`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum FiberThreshold {
+    /// A threshold of 1.2V, the default.
+    Low,
+    /// A threshold of 2.0V.
+    High,
+}
+
+/// The configuration of a port for fiber operation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct FiberConfig {
+    /// The signal detect threshold.
+    pub threshold: FiberThreshold,
+    /// The duplex mode to force via `Ctrl12::force_duplex`.
+    pub duplex: Duplex,
+    /// Enable far-end fault detection via `Ctrl13::disable_far_end_fault`.
+    pub far_end_fault_detection: bool,
+}
+
+impl Default for FiberConfig {
+    fn default() -> Self {
+        FiberConfig {
+            threshold: FiberThreshold::Low,
+            duplex: Duplex::Full,
+            far_end_fault_detection: true,
+        }
+    }
+}
+
+impl<T> Smi<T> {
+    /// Configure the given port for 100BASE-FX fiber operation.
+    ///
+    /// Writes the port's bit within `FiberSignalThreshold`, disables auto-negotiation and forces
+    /// 100Mbps along with the configured duplex mode via `Ctrl12`, and sets far-end fault
+    /// detection via `Ctrl13`. All other fields are preserved.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3, which has no fiber support.
+    pub fn set_fiber_mode<E>(&mut self, id: PortId, config: FiberConfig) -> Result<(), PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        if id == PortId::Port3 {
+            return Err(PortError::UnsupportedRegister);
+        }
+        let high = config.threshold == FiberThreshold::High;
+        self.fiber_signal_threshold()
+            .modify(|w| match id {
+                PortId::Port1 => w.port1().bit(high),
+                PortId::Port2 => w.port2().bit(high),
+                // Rejected above.
+                PortId::Port3 => w,
+            })
+            .map_err(PortError::Interface)?;
+        let mut port = self.port(id);
+        port.force_link(Speed::Mbps100, config.duplex)?;
+        port.set_far_end_fault_detection(config.far_end_fault_detection)
+    }
+}
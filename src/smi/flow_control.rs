@@ -0,0 +1,81 @@
+//! Flow control configuration spread across the global and per-port control registers.
+
+use super::{PortId, Read, Smi, Write};
+
+/// The flow control configuration of the switch.
+///
+/// Groups `Gc1::tx_flow_control`, `Gc1::rx_flow_control` and the `Ctrl2::force_flow_control` bit
+/// of each port so that they may be read and written as a unit.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct FlowControlConfig {
+    /// Enable transmit flow control, as held by `Gc1::tx_flow_control`.
+    pub global_tx: bool,
+    /// Enable receive flow control, as held by `Gc1::rx_flow_control`.
+    pub global_rx: bool,
+    /// Force flow control on each port regardless of the auto-negotiation result, as held by
+    /// `Ctrl2::force_flow_control`. Element `n` represents port `n + 1`.
+    pub port_force: [bool; 3],
+}
+
+impl<T> Smi<T> {
+    /// Read the flow control configuration from `Gc1` and the `Ctrl2` register of each port.
+    pub fn flow_control(&mut self) -> Result<FlowControlConfig, T::Error>
+    where
+        T: Read,
+    {
+        let gc1 = self.gc1().read()?;
+        let mut config = FlowControlConfig {
+            global_tx: gc1.read().tx_flow_control().bit(),
+            global_rx: gc1.read().rx_flow_control().bit(),
+            port_force: [false; 3],
+        };
+        for (&id, force) in PortId::ALL.iter().zip(config.port_force.iter_mut()) {
+            let ctrl2 = self.port(id).ctrl2().read()?;
+            *force = ctrl2.read().force_flow_control().bit();
+        }
+        Ok(config)
+    }
+
+    /// Write the given flow control configuration to `Gc1` and the `Ctrl2` register of each port.
+    ///
+    /// All other fields of the affected registers are preserved.
+    pub fn set_flow_control<E>(&mut self, config: FlowControlConfig) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.gc1().modify(|w| {
+            w.tx_flow_control()
+                .bit(config.global_tx)
+                .rx_flow_control()
+                .bit(config.global_rx)
+        })?;
+        for (&id, &force) in PortId::ALL.iter().zip(config.port_force.iter()) {
+            self.port(id)
+                .ctrl2()
+                .modify(|w| w.force_flow_control().bit(force))?;
+        }
+        Ok(())
+    }
+
+    /// Whether or not the pause iteration limit is enabled via `ForcePauseOff`.
+    pub fn pause_iteration_limit(&mut self) -> Result<bool, T::Error>
+    where
+        T: Read,
+    {
+        let reg = self.force_pause_off().read()?;
+        Ok(reg.read().iteration_limit_enable().bit_is_set())
+    }
+
+    /// Enable or disable the pause iteration limit via `ForcePauseOff`.
+    ///
+    /// The reserved bits of the register are preserved.
+    pub fn set_pause_iteration_limit<E>(&mut self, enable: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.force_pause_off()
+            .modify(|w| w.iteration_limit_enable().bit(enable))
+    }
+}
@@ -0,0 +1,35 @@
+//! The raw framing of SMI transactions, for implementing `smi::Read` and `smi::Write` directly on
+//! top of a bit-banged or byte-oriented MDIO interface.
+//!
+//! Each transaction is preceded by a preamble of 32 `1` bits and consists of 16 control bits
+//! followed by 16 data bits, transmitted most significant bit first. The control bits are
+//! produced by `read_ctrl_bits` and `write_ctrl_bits`, and encode the 8-bit register address
+//! across the PHYAD and REGAD fields, with the top bit of PHYAD selecting a read. Only the lower 8
+//! data bits are used.
+//!
+//! During a read, the two turnaround bits (the lowest two control bits) and the data bits are
+//! driven by the switch rather than the host.
+
+use super::{read_ctrl_bits, write_ctrl_bits};
+
+/// The preamble transmitted before each transaction.
+pub const PREAMBLE: [u8; 4] = [0xFF; 4];
+
+/// The control bytes transmitted by the host to begin a read of the given register.
+///
+/// The 16 data bits that follow are driven by the switch.
+pub fn read_command(reg_addr: u8) -> [u8; 2] {
+    read_ctrl_bits(reg_addr).to_be_bytes()
+}
+
+/// The control and data bytes transmitted by the host to write the given data to the given
+/// register.
+pub fn write_command(reg_addr: u8, data: u8) -> [u8; 4] {
+    let [c0, c1] = write_ctrl_bits(reg_addr).to_be_bytes();
+    [c0, c1, 0x00, data]
+}
+
+/// Extract the register value from the 16 data bits returned by the switch during a read.
+pub fn read_data(bytes: [u8; 2]) -> u8 {
+    bytes[1]
+}
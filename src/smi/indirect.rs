@@ -0,0 +1,167 @@
+//! Access to the switch's internal tables via the indirect access registers.
+//!
+//! The static MAC, VLAN, dynamic MAC and MIB counter tables are not directly addressable via the
+//! SMI. Instead, a table and entry are selected via the `IndirectAccessCtrl0` and
+//! `IndirectAccessCtrl1` registers and the entry's data is transferred via the `IndirectData*`
+//! registers. Writing `IndirectAccessCtrl1` triggers the read or write operation.
+
+use super::{Address, Read, Smi, Write};
+
+/// Provides raw access to the indirectly accessible tables.
+///
+/// The table-specific wrappers (e.g. `StaticMacTable`, `VlanTable`) are built on top of this type.
+pub struct Indirect<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+}
+
+/// Errors that may occur while accessing one of the indirectly accessible tables.
+#[derive(Debug)]
+pub enum IndirectError<E> {
+    /// An error occurred on the SMI interface.
+    Interface(E),
+    /// The given entry index exceeds the number of entries in the table, or the given indirect
+    /// address exceeds `Indirect::MAX_ADDR`.
+    EntryOutOfRange,
+    /// The entry's data was not ready within the poll budget.
+    Timeout,
+}
+
+/// The tables that may be selected via `IndirectAccessCtrl0::table_select`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[repr(u8)]
+pub enum Table {
+    /// The static MAC address table, selected via `0b00`.
+    StaticMac = 0b00,
+    /// The VLAN table, selected via `0b01`.
+    Vlan = 0b01,
+    /// The dynamic MAC address table, selected via `0b10`. Read-only.
+    DynamicMac = 0b10,
+    /// The MIB counters, selected via `0b11`. Read-only.
+    MibCounter = 0b11,
+}
+
+/// The data transferred via the indirect data registers.
+pub(crate) type Data = [u8; 9];
+
+/// The indirect data registers in the order in which they appear within `Data`.
+const DATA_ADDRS: [Address; 9] = [
+    Address::IndirectData8,
+    Address::IndirectData7,
+    Address::IndirectData6,
+    Address::IndirectData5,
+    Address::IndirectData4,
+    Address::IndirectData3,
+    Address::IndirectData2,
+    Address::IndirectData1,
+    Address::IndirectData0,
+];
+
+impl<T> Smi<T> {
+    /// Raw access to the indirectly accessible tables.
+    pub fn indirect(&mut self) -> Indirect<'_, T> {
+        Indirect { smi: self }
+    }
+}
+
+impl<'smi, T> Indirect<'smi, T> {
+    /// The maximum indirect address, as the address is 10 bits wide.
+    pub const MAX_ADDR: u16 = 0x3FF;
+
+    /// Read the entry at the given indirect address of the given table.
+    ///
+    /// Element `0` holds `IndirectData8` (bits `71:64`) and element `8` holds `IndirectData0` (bits
+    /// `7:0`), i.e. the bytes are ordered most significant first, matching the register addresses.
+    ///
+    /// Returns `IndirectError::EntryOutOfRange` without accessing any register if `addr` exceeds
+    /// `MAX_ADDR`.
+    pub fn read<E>(&mut self, table: Table, addr: u16) -> Result<[u8; 9], IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        check_addr(addr)?;
+        self.trigger(true, table, addr)
+            .map_err(IndirectError::Interface)?;
+        self.read_data().map_err(IndirectError::Interface)
+    }
+
+    /// Write the given data to the entry at the given indirect address of the given table.
+    ///
+    /// The data is ordered the same as for `read`. Returns `IndirectError::EntryOutOfRange`
+    /// without accessing any register if `addr` exceeds `MAX_ADDR`.
+    pub fn write(
+        &mut self,
+        table: Table,
+        addr: u16,
+        data: &[u8; 9],
+    ) -> Result<(), IndirectError<T::Error>>
+    where
+        T: Write,
+    {
+        check_addr(addr)?;
+        for (&addr, &byte) in DATA_ADDRS.iter().zip(data.iter()) {
+            self.smi
+                .0
+                .write(addr.into(), byte)
+                .map_err(IndirectError::Interface)?;
+        }
+        self.trigger(false, table, addr)
+            .map_err(IndirectError::Interface)
+    }
+
+    /// Re-read the indirect data registers without triggering a new read operation.
+    ///
+    /// This is useful for the dynamic MAC and MIB counter tables where the datasheet specifies to
+    /// re-read the data until a status bit indicates that it is ready.
+    pub fn read_data(&mut self) -> Result<[u8; 9], T::Error>
+    where
+        T: Read,
+    {
+        let mut data = Data::default();
+        for (&addr, byte) in DATA_ADDRS.iter().zip(data.iter_mut()) {
+            *byte = self.smi.0.read(addr.into())?;
+        }
+        Ok(data)
+    }
+
+    /// Select the given table entry and trigger either a read or a write operation.
+    fn trigger(&mut self, read: bool, table: Table, addr: u16) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.smi.indirect_access_ctrl0().write(|w| {
+            w.read_high_write_low()
+                .bit(read)
+                .table_select()
+                .bits(table as u8)
+                .indirect_addr_high()
+                .bits((addr >> 8) as u8)
+        })?;
+        self.smi
+            .indirect_access_ctrl1()
+            .write(|w| w.indirect_addr_low().bits(addr as u8))
+    }
+}
+
+/// Ensure the given indirect address fits within the 10-bit address fields.
+fn check_addr<E>(addr: u16) -> Result<(), IndirectError<E>> {
+    if addr > Indirect::<()>::MAX_ADDR {
+        return Err(IndirectError::EntryOutOfRange);
+    }
+    Ok(())
+}
+
+/// Produce bits `63:0` of the given data.
+pub(crate) fn data_to_u64(data: &Data) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[1..]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Produce data with the given bits `63:0`.
+pub(crate) fn u64_to_data(bits: u64) -> Data {
+    let mut data = Data::default();
+    data[1..].copy_from_slice(&bits.to_be_bytes());
+    data
+}
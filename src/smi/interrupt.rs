@@ -0,0 +1,118 @@
+//! Decoding of the link change interrupt registers.
+
+use super::{Read, Smi, Write};
+
+/// A set of the switch's link change interrupts.
+///
+/// Describes either the pending interrupts held by `LinkChangeInterrupt` or the enabled
+/// interrupts held by `InterruptEnable`, as both registers share the same layout:
+///
+/// | Bit | Interrupt              |
+/// | --- | ---------------------- |
+/// | `7` | Port 1 or port 2 link  |
+/// | `2` | Port 3 link            |
+/// | `1` | Port 2 link            |
+/// | `0` | Port 1 link            |
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Interrupts {
+    /// The link status of port 1 or port 2 changed in the analog interface.
+    pub p1_p2_link_change: bool,
+    /// The link status of port 3 changed.
+    pub p3_link_change: bool,
+    /// The link status of port 2 changed.
+    pub p2_link_change: bool,
+    /// The link status of port 1 changed.
+    pub p1_link_change: bool,
+}
+
+impl Interrupts {
+    /// No interrupts.
+    pub const NONE: Self = Interrupts {
+        p1_p2_link_change: false,
+        p3_link_change: false,
+        p2_link_change: false,
+        p1_link_change: false,
+    };
+    /// All link change interrupts.
+    pub const ALL: Self = Interrupts {
+        p1_p2_link_change: true,
+        p3_link_change: true,
+        p2_link_change: true,
+        p1_link_change: true,
+    };
+
+    /// Whether or not any interrupt is set.
+    pub fn any(&self) -> bool {
+        *self != Self::NONE
+    }
+}
+
+impl<T> Smi<T> {
+    /// Read the pending interrupts from `LinkChangeInterrupt`.
+    pub fn interrupt_status(&mut self) -> Result<Interrupts, T::Error>
+    where
+        T: Read,
+    {
+        let reg = self.link_change_interrupt().read()?;
+        let bits: u8 = reg.into();
+        Ok(Interrupts::from(bits))
+    }
+
+    /// Read the enabled interrupts from `InterruptEnable`.
+    pub fn enabled_interrupts(&mut self) -> Result<Interrupts, T::Error>
+    where
+        T: Read,
+    {
+        let reg = self.interrupt_enable().read()?;
+        Ok(Interrupts::from(reg.read().reg().bits()))
+    }
+
+    /// Write the given set of enabled interrupts to `InterruptEnable`.
+    pub fn set_enabled_interrupts(&mut self, interrupts: Interrupts) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.interrupt_enable()
+            .write(|w| w.reg().bits(interrupts.into()))
+    }
+
+    /// Clear all pending interrupts, returning the interrupts that were cleared.
+    ///
+    /// The `LinkChangeInterrupt` bits are cleared by writing `1`, so the pending interrupts are
+    /// read and written back. This avoids clearing an interrupt that arrives in between.
+    pub fn clear_interrupts<E>(&mut self) -> Result<Interrupts, E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let pending = self.interrupt_status()?;
+        if pending.any() {
+            let bits = u8::from(pending);
+            self.0
+                .write(super::Address::LinkChangeInterrupt.into(), bits)?;
+        }
+        Ok(pending)
+    }
+}
+
+impl From<u8> for Interrupts {
+    /// Reserved bits are ignored.
+    fn from(bits: u8) -> Self {
+        Interrupts {
+            p1_p2_link_change: bits & (1 << 7) != 0,
+            p3_link_change: bits & (1 << 2) != 0,
+            p2_link_change: bits & (1 << 1) != 0,
+            p1_link_change: bits & 1 != 0,
+        }
+    }
+}
+
+impl From<Interrupts> for u8 {
+    fn from(interrupts: Interrupts) -> Self {
+        (u8::from(interrupts.p1_p2_link_change) << 7)
+            | (u8::from(interrupts.p3_link_change) << 2)
+            | (u8::from(interrupts.p2_link_change) << 1)
+            | u8::from(interrupts.p1_link_change)
+    }
+}
@@ -0,0 +1,94 @@
+//! A wrapper that records each SMI register write for later inspection or replay.
+
+use super::{Read, Smi, Transaction, Write};
+use crate::TransactionKind;
+
+/// Wraps an `smi::Read` and/or `smi::Write` implementation, recording up to `N` successful
+/// writes.
+///
+/// Useful for capturing a bring-up sequence once and replaying it onto other devices. Reads are
+/// not recorded.
+#[derive(Clone, Debug, Default)]
+pub struct Journaled<T, const N: usize> {
+    inner: T,
+    journal: heapless::Vec<Transaction, N>,
+    overflowed: bool,
+}
+
+impl<T, const N: usize> Journaled<T, N> {
+    /// Wrap the given interface with an empty journal.
+    pub fn new(inner: T) -> Self {
+        Journaled {
+            inner,
+            journal: heapless::Vec::new(),
+            overflowed: false,
+        }
+    }
+
+    /// The writes recorded so far, in the order in which they were performed.
+    pub fn journal(&self) -> &[Transaction] {
+        &self.journal
+    }
+
+    /// Whether or not any write was not recorded because the journal was full.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Clear the journal.
+    pub fn clear(&mut self) {
+        self.journal.clear();
+        self.overflowed = false;
+    }
+
+    /// Perform each recorded write on the given SMI interface, in order.
+    pub fn replay<U>(&self, into: &mut Smi<U>) -> Result<(), U::Error>
+    where
+        U: Write,
+    {
+        for t in &self.journal {
+            into.0.write(t.reg_addr, t.data)?;
+        }
+        Ok(())
+    }
+
+    /// Access the wrapped interface.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped interface.
+    ///
+    /// Writes made directly via the wrapped interface are not recorded.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner interface.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read, const N: usize> Read for Journaled<T, N> {
+    type Error = T::Error;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.inner.read(reg_addr)
+    }
+}
+
+impl<T: Write, const N: usize> Write for Journaled<T, N> {
+    type Error = T::Error;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.inner.write(reg_addr, data)?;
+        let t = Transaction {
+            kind: TransactionKind::Write,
+            reg_addr,
+            data,
+        };
+        if self.journal.push(t).is_err() {
+            self.overflowed = true;
+        }
+        Ok(())
+    }
+}
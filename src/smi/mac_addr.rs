@@ -0,0 +1,90 @@
+//! Access to the MAC addresses spread across consecutive 8-bit registers.
+
+use super::{Address, Read, Smi, Write};
+
+/// Identifies one of the two station MAC addresses used for self-address filtering.
+///
+/// These pair with the `self_addr_filtering_enable_maca1` and `self_addr_filtering_enable_maca2`
+/// fields of the port `Ctrl5` registers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Station {
+    /// Station MAC address 1, held by `Station1MacAddr0..=Station1MacAddr5`.
+    Station1,
+    /// Station MAC address 2, held by `Station2MacAddr0..=Station2MacAddr5`.
+    Station2,
+}
+
+impl<T> Smi<T> {
+    /// Read the switch MAC address from `MacAddr0..=MacAddr5`.
+    ///
+    /// `MacAddr0` holds the most significant byte (bits `47:40`), i.e. the first byte of the
+    /// returned address.
+    pub fn mac_addr(&mut self) -> Result<[u8; 6], T::Error>
+    where
+        T: Read,
+    {
+        self.read_mac_addr(Address::MacAddr0)
+    }
+
+    /// Write the switch MAC address to `MacAddr0..=MacAddr5`.
+    ///
+    /// The first byte of the address is written to `MacAddr0`.
+    pub fn set_mac_addr(&mut self, mac_addr: [u8; 6]) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.write_mac_addr(Address::MacAddr0, mac_addr)
+    }
+
+    /// Read the MAC address of the given station.
+    ///
+    /// As with the switch MAC address, the first register holds the most significant byte.
+    pub fn station_mac_addr(&mut self, station: Station) -> Result<[u8; 6], T::Error>
+    where
+        T: Read,
+    {
+        self.read_mac_addr(station.first_addr())
+    }
+
+    /// Write the MAC address of the given station.
+    pub fn set_station_mac_addr(
+        &mut self,
+        station: Station,
+        mac_addr: [u8; 6],
+    ) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.write_mac_addr(station.first_addr(), mac_addr)
+    }
+
+    /// Read the MAC address held by the six consecutive registers starting at `first`.
+    fn read_mac_addr(&mut self, first: Address) -> Result<[u8; 6], T::Error>
+    where
+        T: Read,
+    {
+        let mut mac_addr = [0u8; 6];
+        self.read_consecutive(first, &mut mac_addr)?;
+        Ok(mac_addr)
+    }
+
+    /// Write the MAC address to the six consecutive registers starting at `first`.
+    fn write_mac_addr(&mut self, first: Address, mac_addr: [u8; 6]) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.write_consecutive(first, &mac_addr)
+    }
+}
+
+impl Station {
+    /// The address of the register holding the most significant byte of the station's address.
+    fn first_addr(self) -> Address {
+        match self {
+            Station::Station1 => Address::Station1MacAddr0,
+            Station::Station2 => Address::Station2MacAddr0,
+        }
+    }
+}
@@ -0,0 +1,329 @@
+//! High-level access to the MAC address tables.
+
+use super::indirect::{self, IndirectError, Table};
+use super::{Gc0, Gc1, Read, Smi, Write};
+
+/// Provides access to the static MAC address table via the indirect access registers.
+pub struct StaticMacTable<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+}
+
+/// Provides read access to the dynamic (learned) MAC address table via the indirect access
+/// registers.
+pub struct DynamicMacTable<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+}
+
+/// A single entry within the static MAC address table.
+///
+/// Each entry occupies 58 bits of the indirect data registers, laid out as follows:
+///
+/// | Bits     | Field                |
+/// | -------- | -------------------- |
+/// | `57:54`  | FID                  |
+/// | `53`     | Use FID              |
+/// | `52`     | Override             |
+/// | `51`     | Valid                |
+/// | `50:48`  | Forwarding ports     |
+/// | `47:0`   | MAC address          |
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct StaticMacEntry {
+    /// The filter ID, representing one of the 16 active VLANs.
+    pub fid: u8,
+    /// Whether or not to use the `fid` when looking up the entry.
+    pub use_fid: bool,
+    /// Override the port's spanning tree "transmit enable" and "receive enable" settings.
+    pub override_stp: bool,
+    /// Whether or not the entry is valid.
+    pub valid: bool,
+    /// The ports to which matching frames are forwarded, where bit `n` represents port `n + 1`.
+    pub forwarding_ports: u8,
+    /// The MAC address, most significant byte first.
+    pub mac_addr: [u8; 6],
+}
+
+/// A single entry within the dynamic MAC address table.
+///
+/// Each entry occupies 72 bits of the indirect data registers, laid out as follows:
+///
+/// | Bits     | Field                       |
+/// | -------- | --------------------------- |
+/// | `71`     | Data not ready              |
+/// | `70:67`  | Reserved                    |
+/// | `66`     | MAC empty                   |
+/// | `65:56`  | Number of valid entries     |
+/// | `55:54`  | Time stamp                  |
+/// | `53:52`  | Source port                 |
+/// | `51:48`  | FID                         |
+/// | `47:0`   | MAC address                 |
+///
+/// Note that the "MAC empty" bit is represented by `DynamicMacTable::entry` returning `None`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct DynamicMacEntry {
+    /// The 2-bit counter used for internal aging.
+    pub timestamp: u8,
+    /// The port on which the address was learned, where `0` represents port 1.
+    pub source_port: u8,
+    /// The filter ID.
+    pub fid: u8,
+    /// The MAC address, most significant byte first.
+    pub mac_addr: [u8; 6],
+}
+
+impl<T> Smi<T> {
+    /// Access the static MAC address table.
+    pub fn static_mac_table(&mut self) -> StaticMacTable<'_, T> {
+        StaticMacTable { smi: self }
+    }
+
+    /// Access the dynamic MAC address table.
+    pub fn dynamic_mac_table(&mut self) -> DynamicMacTable<'_, T> {
+        DynamicMacTable { smi: self }
+    }
+
+    /// Flush the dynamic MAC address table via `Gc0::flush_dynamic_mac_table`.
+    ///
+    /// The bit is written as `1` and self-clears once the flush completes. The bit is polled at
+    /// most `max_polls` times before returning `PollError::Timeout`. A `max_polls` of `0` triggers
+    /// the flush without waiting for it to complete.
+    pub fn flush_dynamic_mac_table<E>(
+        &mut self,
+        max_polls: usize,
+    ) -> Result<(), crate::PollError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Gc0>()
+            .modify(|w| w.flush_dynamic_mac_table().set_bit())
+            .map_err(crate::PollError::Interface)?;
+        self.poll_gc0(max_polls, |gc0| {
+            gc0.read().flush_dynamic_mac_table().bit_is_clear()
+        })
+    }
+
+    /// Flush the static MAC address table via `Gc0::flush_static_mac_table`.
+    ///
+    /// Behaves the same as `flush_dynamic_mac_table`.
+    pub fn flush_static_mac_table<E>(&mut self, max_polls: usize) -> Result<(), crate::PollError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Gc0>()
+            .modify(|w| w.flush_static_mac_table().set_bit())
+            .map_err(crate::PollError::Interface)?;
+        self.poll_gc0(max_polls, |gc0| {
+            gc0.read().flush_static_mac_table().bit_is_clear()
+        })
+    }
+
+    /// Enable or disable aging of dynamic MAC table entries via `Gc1::aging`.
+    pub fn set_aging<E>(&mut self, enable: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Gc1>().modify(|w| w.aging().bit(enable))
+    }
+
+    /// Enable or disable fast aging of dynamic MAC table entries via `Gc1::fast_age`.
+    pub fn set_fast_age<E>(&mut self, enable: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Gc1>().modify(|w| w.fast_age().bit(enable))
+    }
+
+    /// Poll `Gc0` at most `max_polls` times until `done` returns `true`.
+    fn poll_gc0<E>(
+        &mut self,
+        max_polls: usize,
+        done: impl Fn(&Gc0) -> bool,
+    ) -> Result<(), crate::PollError<E>>
+    where
+        T: Read<Error = E>,
+    {
+        if max_polls == 0 {
+            return Ok(());
+        }
+        for _ in 0..max_polls {
+            let gc0 = self
+                .reg::<Gc0>()
+                .read()
+                .map_err(crate::PollError::Interface)?;
+            if done(&gc0) {
+                return Ok(());
+            }
+        }
+        Err(crate::PollError::Timeout)
+    }
+}
+
+impl<'smi, T> StaticMacTable<'smi, T> {
+    /// The number of entries in the static MAC address table.
+    pub const LEN: u8 = 8;
+
+    /// Read the entry at the given index.
+    ///
+    /// Returns `IndirectError::EntryOutOfRange` if `index` is not less than `LEN`.
+    pub fn read_entry<E>(&mut self, index: u8) -> Result<StaticMacEntry, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        check_static_index(index)?;
+        let data = self.smi.indirect().read(Table::StaticMac, index.into())?;
+        Ok(StaticMacEntry::from(indirect::data_to_u64(&data)))
+    }
+
+    /// Write the given entry to the given index.
+    ///
+    /// Returns `IndirectError::EntryOutOfRange` if `index` is not less than `LEN`.
+    pub fn write_entry(
+        &mut self,
+        index: u8,
+        entry: &StaticMacEntry,
+    ) -> Result<(), IndirectError<T::Error>>
+    where
+        T: Write,
+    {
+        check_static_index(index)?;
+        let data = indirect::u64_to_data((*entry).into());
+        self.smi
+            .indirect()
+            .write(Table::StaticMac, index.into(), &data)
+    }
+}
+
+impl<'smi, T> DynamicMacTable<'smi, T> {
+    /// The maximum number of entries in the dynamic MAC address table.
+    pub const CAPACITY: u16 = 1024;
+
+    /// The number of times the CPU read status is polled before giving up on an entry.
+    pub const MAX_READ_POLLS: usize = 16;
+
+    /// The number of valid entries currently in the table.
+    pub fn len<E>(&mut self) -> Result<u16, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let data = self.read_raw(0)?;
+        if mac_empty(&data) {
+            return Ok(0);
+        }
+        // The field holds the number of valid entries minus one.
+        let count = (u16::from(data[0] & 0b11) << 8) | u16::from(data[1]);
+        Ok(count + 1)
+    }
+
+    /// Whether or not the table is currently empty.
+    pub fn is_empty<E>(&mut self) -> Result<bool, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let data = self.read_raw(0)?;
+        Ok(mac_empty(&data))
+    }
+
+    /// Read the entry at the given index.
+    ///
+    /// Returns `Ok(None)` if the table is empty. Returns `IndirectError::EntryOutOfRange` if
+    /// `index` is not less than `CAPACITY`, or `IndirectError::Timeout` if the data is not ready
+    /// within `MAX_READ_POLLS` reads.
+    pub fn entry<E>(&mut self, index: u16) -> Result<Option<DynamicMacEntry>, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        if index >= Self::CAPACITY {
+            return Err(IndirectError::EntryOutOfRange);
+        }
+        let data = self.read_raw(index)?;
+        if mac_empty(&data) {
+            return Ok(None);
+        }
+        let data = indirect::data_to_u64(&data);
+        let entry = DynamicMacEntry {
+            timestamp: ((data >> 54) & 0b11) as u8,
+            source_port: ((data >> 52) & 0b11) as u8,
+            fid: ((data >> 48) & 0xF) as u8,
+            mac_addr: mac_addr_from_u64(data),
+        };
+        Ok(Some(entry))
+    }
+
+    /// Trigger a read of the entry at the given index and poll until the data is ready.
+    ///
+    /// Returns `IndirectError::Timeout` if the data is not ready within `MAX_READ_POLLS` reads.
+    fn read_raw<E>(&mut self, index: u16) -> Result<indirect::Data, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let mut access = self.smi.indirect();
+        let mut data = access.read(Table::DynamicMac, index)?;
+        for _ in 1..Self::MAX_READ_POLLS {
+            if !data_not_ready(&data) {
+                return Ok(data);
+            }
+            data = access.read_data().map_err(IndirectError::Interface)?;
+        }
+        if data_not_ready(&data) {
+            return Err(IndirectError::Timeout);
+        }
+        Ok(data)
+    }
+}
+
+impl From<u64> for StaticMacEntry {
+    fn from(data: u64) -> Self {
+        StaticMacEntry {
+            fid: ((data >> 54) & 0xF) as u8,
+            use_fid: data & (1 << 53) != 0,
+            override_stp: data & (1 << 52) != 0,
+            valid: data & (1 << 51) != 0,
+            forwarding_ports: ((data >> 48) & 0b111) as u8,
+            mac_addr: mac_addr_from_u64(data),
+        }
+    }
+}
+
+impl From<StaticMacEntry> for u64 {
+    fn from(entry: StaticMacEntry) -> Self {
+        (u64::from(entry.fid & 0xF) << 54)
+            | (u64::from(entry.use_fid) << 53)
+            | (u64::from(entry.override_stp) << 52)
+            | (u64::from(entry.valid) << 51)
+            | (u64::from(entry.forwarding_ports & 0b111) << 48)
+            | mac_addr_to_u64(entry.mac_addr)
+    }
+}
+
+/// Ensure the given index lies within the static MAC address table.
+fn check_static_index<E>(index: u8) -> Result<(), IndirectError<E>> {
+    if index >= StaticMacTable::<()>::LEN {
+        return Err(IndirectError::EntryOutOfRange);
+    }
+    Ok(())
+}
+
+/// Whether or not the "data not ready" bit (71) is set.
+fn data_not_ready(data: &indirect::Data) -> bool {
+    data[0] & 0b1000_0000 != 0
+}
+
+/// Whether or not the "MAC empty" bit (66) is set.
+fn mac_empty(data: &indirect::Data) -> bool {
+    data[0] & 0b100 != 0
+}
+
+/// Decode the MAC address from bits `47:0`.
+fn mac_addr_from_u64(data: u64) -> [u8; 6] {
+    let bytes = data.to_be_bytes();
+    [bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]
+}
+
+/// Encode the MAC address into bits `47:0`.
+fn mac_addr_to_u64(mac_addr: [u8; 6]) -> u64 {
+    let [a, b, c, d, e, f] = mac_addr;
+    u64::from_be_bytes([0, 0, a, b, c, d, e, f])
+}
@@ -0,0 +1,183 @@
+//! High-level access to the per-port management information base (MIB) counters.
+
+use super::indirect::{self, IndirectError, Table};
+use super::{PortId, Read, Smi, Write};
+
+/// Provides read access to the MIB counters via the indirect access registers.
+pub struct MibCounters<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+}
+
+/// The set of MIB counters available for each port.
+///
+/// The discriminant of each counter is its indirect address offset for port 1. The counters for
+/// ports 2 and 3 follow at a stride of `MibCounter::PORT_STRIDE`, with the exception of the dropped packet
+/// counters which are grouped together for all ports. See `MibCounter::indirect_addr`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[repr(u16)]
+pub enum MibCounter {
+    /// Rx low priority (default) octet count including bad packets.
+    RxLoPriorityByte = 0x00,
+    /// Rx high priority octet count including bad packets.
+    RxHiPriorityByte = 0x01,
+    /// Rx undersize packets with good CRC.
+    RxUndersizePkt = 0x02,
+    /// Rx fragment packets with bad CRC, symbol errors or alignment errors.
+    RxFragments = 0x03,
+    /// Rx oversize packets with good CRC.
+    RxOversize = 0x04,
+    /// Rx packets longer than 1522 bytes with either CRC errors, alignment errors or symbol errors.
+    RxJabbers = 0x05,
+    /// Rx packets with invalid data symbol and legal packet size.
+    RxSymbolError = 0x06,
+    /// Rx packets within 64 to 1522 bytes with an integral number of bytes and a bad CRC.
+    RxCrcError = 0x07,
+    /// Rx packets within 64 to 1522 bytes with a non-integral number of bytes and a bad CRC.
+    RxAlignmentError = 0x08,
+    /// Number of MAC control frames received with a 0x8808 EtherType.
+    RxControl8808Pkts = 0x09,
+    /// Number of PAUSE frames received.
+    RxPausePkts = 0x0A,
+    /// Rx good broadcast packets.
+    RxBroadcast = 0x0B,
+    /// Rx good multicast packets.
+    RxMulticast = 0x0C,
+    /// Rx good unicast packets.
+    RxUnicast = 0x0D,
+    /// Total Rx packets of 64 bytes in length.
+    Rx64Octets = 0x0E,
+    /// Total Rx packets of 65 to 127 bytes in length.
+    Rx65To127Octets = 0x0F,
+    /// Total Rx packets of 128 to 255 bytes in length.
+    Rx128To255Octets = 0x10,
+    /// Total Rx packets of 256 to 511 bytes in length.
+    Rx256To511Octets = 0x11,
+    /// Total Rx packets of 512 to 1023 bytes in length.
+    Rx512To1023Octets = 0x12,
+    /// Total Rx packets of 1024 to 1522 bytes in length.
+    Rx1024To1522Octets = 0x13,
+    /// Tx low priority good octet count, including PAUSE packets.
+    TxLoPriorityByte = 0x14,
+    /// Tx high priority good octet count, including PAUSE packets.
+    TxHiPriorityByte = 0x15,
+    /// Number of times a collision is detected later than 512 bit-times into the Tx of a packet.
+    TxLateCollision = 0x16,
+    /// Number of PAUSE frames transmitted.
+    TxPausePkts = 0x17,
+    /// Tx good broadcast packets.
+    TxBroadcastPkts = 0x18,
+    /// Tx good multicast packets.
+    TxMulticastPkts = 0x19,
+    /// Tx good unicast packets.
+    TxUnicastPkts = 0x1A,
+    /// Tx packets by a port for which the first Tx attempt is delayed due to the busy medium.
+    TxDeferred = 0x1B,
+    /// Tx total collisions, half duplex only.
+    TxTotalCollision = 0x1C,
+    /// Number of frames dropped due to excessive collisions.
+    TxExcessiveCollision = 0x1D,
+    /// Successfully transmitted frames on a port for which Tx is inhibited by exactly one collision.
+    TxSingleCollision = 0x1E,
+    /// Successfully transmitted frames on a port for which Tx is inhibited by more than one
+    /// collision.
+    TxMultipleCollision = 0x1F,
+    /// Tx packets dropped due to lack of resources.
+    TxDropPackets = 0x100,
+    /// Rx packets dropped due to lack of resources.
+    RxDropPackets = 0x103,
+}
+
+/// The value read from a MIB counter.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct MibReading {
+    /// The counter value.
+    ///
+    /// The per-port counters are 30 bits wide, while the dropped packet counters are 16 bits wide.
+    pub value: u32,
+    /// Whether or not the counter has overflowed.
+    pub overflow: bool,
+    /// Whether or not the counter value was valid.
+    ///
+    /// This is only `false` in the case that the read status polling was exhausted.
+    pub valid: bool,
+}
+
+/// Bit 31 of a per-port counter, indicating that the counter has overflowed.
+const OVERFLOW: u64 = 1 << 31;
+/// Bit 30 of a per-port counter, indicating that the counter value is valid.
+const COUNT_VALID: u64 = 1 << 30;
+
+impl<T> Smi<T> {
+    /// Access the MIB counters.
+    pub fn mib_counters(&mut self) -> MibCounters<'_, T> {
+        MibCounters { smi: self }
+    }
+}
+
+impl<'smi, T> MibCounters<'smi, T> {
+    /// The number of times the counter is re-read while waiting for its "count valid" flag.
+    pub const MAX_READ_POLLS: usize = 16;
+
+    /// Read the given counter for the given port.
+    pub fn read_counter<E>(
+        &mut self,
+        port: PortId,
+        counter: MibCounter,
+    ) -> Result<MibReading, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let addr = counter.indirect_addr(port);
+        let mut access = self.smi.indirect();
+        let mut data = indirect::data_to_u64(&access.read(Table::MibCounter, addr)?);
+        if counter.is_drop_counter() {
+            let value = (data & 0xFFFF) as u32;
+            let reading = MibReading {
+                value,
+                overflow: false,
+                valid: true,
+            };
+            return Ok(reading);
+        }
+        // The datasheet specifies to re-read the data until the count is valid.
+        for _ in 1..Self::MAX_READ_POLLS {
+            if data & COUNT_VALID != 0 {
+                break;
+            }
+            let raw = access.read_data().map_err(IndirectError::Interface)?;
+            data = indirect::data_to_u64(&raw);
+        }
+        Ok(MibReading {
+            value: (data & 0x3FFF_FFFF) as u32,
+            overflow: data & OVERFLOW != 0,
+            valid: data & COUNT_VALID != 0,
+        })
+    }
+}
+
+impl MibCounter {
+    /// The indirect address offset between the per-port counters of consecutive ports.
+    pub const PORT_STRIDE: u16 = 0x20;
+
+    /// Whether or not this is one of the 16-bit dropped packet counters.
+    pub fn is_drop_counter(self) -> bool {
+        matches!(self, MibCounter::TxDropPackets | MibCounter::RxDropPackets)
+    }
+
+    /// The indirect address of the counter for the given port.
+    ///
+    /// The per-port counters are located at `offset + port_index * PORT_STRIDE`, while the
+    /// dropped packet counters are located at `offset + port_index`.
+    pub fn indirect_addr(self, port: PortId) -> u16 {
+        let port_ix = port.index() as u16;
+        if self.is_drop_counter() {
+            self as u16 + port_ix
+        } else {
+            self as u16 + port_ix * Self::PORT_STRIDE
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Port mirroring configuration spread across the `Ctrl1` register of each port.
+
+use super::{PortId, Read, Smi, Write};
+
+/// The direction of traffic mirrored from a port to the sniffer port.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Direction {
+    /// Mirror packets received on the port, as held by `Ctrl1::receive_sniff`.
+    Rx,
+    /// Mirror packets transmitted from the port, as held by `Ctrl1::transmit_sniff`.
+    Tx,
+    /// Mirror packets in both directions.
+    Both,
+}
+
+/// The port mirroring configuration of the switch.
+///
+/// Groups the `Ctrl1::sniffer_port`, `Ctrl1::receive_sniff` and `Ctrl1::transmit_sniff` bits of
+/// each port so that they may be written as a unit.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct MirrorConfig {
+    /// The port to which mirrored packets are forwarded.
+    pub sniffer_port: PortId,
+    /// The direction mirrored from each port, or `None` if the port is not mirrored. Element `n`
+    /// represents port `n + 1`.
+    pub mirrored: [Option<Direction>; 3],
+}
+
+impl Direction {
+    /// Whether or not received packets are mirrored.
+    pub fn rx(self) -> bool {
+        match self {
+            Direction::Rx | Direction::Both => true,
+            Direction::Tx => false,
+        }
+    }
+
+    /// Whether or not transmitted packets are mirrored.
+    pub fn tx(self) -> bool {
+        match self {
+            Direction::Tx | Direction::Both => true,
+            Direction::Rx => false,
+        }
+    }
+}
+
+impl MirrorConfig {
+    /// A configuration with the given sniffer port and no mirrored ports.
+    pub fn new(sniffer_port: PortId) -> Self {
+        MirrorConfig {
+            sniffer_port,
+            mirrored: [None; 3],
+        }
+    }
+
+    /// Mirror traffic from the given port in the given direction.
+    pub fn mirror(mut self, id: PortId, direction: Direction) -> Self {
+        self.mirrored[id.index()] = Some(direction);
+        self
+    }
+}
+
+impl<T> Smi<T> {
+    /// Write the given mirroring configuration to the `Ctrl1` register of each port.
+    ///
+    /// The sniffer port bit is set only on `config.sniffer_port` and the receive and transmit
+    /// sniff bits are set only on the mirrored ports. All other fields are preserved.
+    pub fn set_mirroring<E>(&mut self, config: MirrorConfig) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        for (&id, &dir) in PortId::ALL.iter().zip(config.mirrored.iter()) {
+            let sniffer = id == config.sniffer_port;
+            let rx = dir.map(Direction::rx).unwrap_or(false);
+            let tx = dir.map(Direction::tx).unwrap_or(false);
+            self.port(id).ctrl1().modify(|w| {
+                w.sniffer_port()
+                    .bit(sniffer)
+                    .receive_sniff()
+                    .bit(rx)
+                    .transmit_sniff()
+                    .bit(tx)
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,692 @@
+//! Items related to addressing the switch's three ports.
+
+use super::{
+    Address, Port1Ctrl0, Port1Ctrl1, Port1Ctrl12, Port1Ctrl13, Port1Ctrl2, Port1Ctrl3, Port1Ctrl4,
+    Port1Ctrl5, Port1LinkMdResult, Port1PhySpecial, Port1Q0IngressRateLimit,
+    Port1Q1IngressRateLimit, Port1Q2IngressRateLimit, Port1Q3IngressRateLimit, Port1Status0,
+    Port1Status1, Read, Register, Smi, Write, W,
+};
+use crate::cable::{CableDiagnostics, VctStatus};
+use crate::link::{Duplex, FlowControl, LinkAbilities, LinkStatus, LoopbackMode, Speed};
+
+/// Identifies one of the three ports of the switch.
+///
+/// Ports 1 and 2 are the PHY ports, while port 3 is the MAC port typically connected to the host.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum PortId {
+    Port1,
+    Port2,
+    Port3,
+}
+
+/// A set of ports, represented as a bitmap where bit `n` represents port `n + 1`.
+///
+/// This matches the layout of the port bitmap fields, e.g. `Ctrl1::port_vlan_membership`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PortSet(u8);
+
+/// An iterator yielding each port within a `PortSet` in order.
+#[derive(Clone, Debug)]
+pub struct PortSetIter(PortSet);
+
+/// Provides access to the registers of a single port.
+pub struct Port<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+    pub id: PortId,
+}
+
+/// A wrapper around an `smi::Read` and/or `smi::Write` implementation for a particular register on
+/// a particular port.
+///
+/// `R` is the port 1 register type, which describes the layout of the equivalent register on each
+/// port. Bits that are only present on some ports are preserved when modifying.
+pub struct PortReg<'port, 'smi, T, R> {
+    pub port: &'port mut Port<'smi, T>,
+    reg: core::marker::PhantomData<R>,
+}
+
+/// The error returned when attempting to access a register that does not exist on port 3.
+#[derive(Debug)]
+pub struct UnsupportedRegister;
+
+/// Errors that may occur during higher-level operations on a port.
+#[derive(Debug)]
+pub enum PortError<E> {
+    /// An error occurred on the SMI interface.
+    Interface(E),
+    /// The operation requires a register that does not exist on port 3.
+    UnsupportedRegister,
+    /// The operation did not complete within the given number of polls.
+    Timeout,
+}
+
+/// A decoded 16-bit VLAN tag, as stored in the port's default tag registers.
+///
+/// | Bits     | Field         |
+/// | -------- | ------------- |
+/// | `15:13`  | User priority |
+/// | `12`     | CFI           |
+/// | `11:0`   | VID           |
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct VlanTag {
+    /// The 3-bit user priority.
+    pub priority: u8,
+    /// The canonical format indicator.
+    pub cfi: bool,
+    /// The 12-bit VLAN ID.
+    pub vid: u16,
+}
+
+impl PortId {
+    /// All ports in order.
+    pub const ALL: &'static [Self] = &[Self::Port1, Self::Port2, Self::Port3];
+
+    /// The zero-based index of the port, i.e. `0` for `Port1`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The bit representing the port within a `PortSet`.
+    fn bit(self) -> u8 {
+        1 << self.index()
+    }
+}
+
+impl PortSet {
+    /// The empty set.
+    pub const EMPTY: Self = PortSet(0);
+    /// The set of all three ports.
+    pub const ALL: Self = PortSet(0b111);
+
+    /// The set containing only the given port.
+    pub fn single(id: PortId) -> Self {
+        PortSet(id.bit())
+    }
+
+    /// The raw bitmap, where bit `n` represents port `n + 1`.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Whether or not the set contains the given port.
+    pub fn contains(self, id: PortId) -> bool {
+        self.0 & id.bit() != 0
+    }
+
+    /// Add the given port to the set.
+    pub fn insert(&mut self, id: PortId) {
+        self.0 |= id.bit();
+    }
+
+    /// Remove the given port from the set.
+    pub fn remove(&mut self, id: PortId) {
+        self.0 &= !id.bit();
+    }
+
+    /// Whether or not the set contains no ports.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// An iterator yielding each port within the set in order.
+    pub fn iter(self) -> PortSetIter {
+        PortSetIter(self)
+    }
+}
+
+impl<T> Smi<T> {
+    /// Access the registers of the port with the given ID.
+    pub fn port(&mut self, id: PortId) -> Port<'_, T> {
+        Port { smi: self, id }
+    }
+}
+
+impl<'smi, T> Port<'smi, T> {
+    /// The distance between the addresses of equivalent registers on consecutive ports.
+    pub const ADDR_STRIDE: u8 = 0x10;
+
+    /// Access a particular register of this port, where `R` is the port 1 equivalent.
+    pub(super) fn reg<R>(&mut self) -> PortReg<'_, 'smi, T, R> {
+        PortReg {
+            port: self,
+            reg: core::marker::PhantomData,
+        }
+    }
+
+    /// Access a register that is only present on ports 1 and 2.
+    fn phy_port_reg<R>(&mut self) -> Result<PortReg<'_, 'smi, T, R>, UnsupportedRegister> {
+        match self.id {
+            PortId::Port1 | PortId::Port2 => Ok(self.reg()),
+            PortId::Port3 => Err(UnsupportedRegister),
+        }
+    }
+
+    /// Access the port's `Ctrl0` register.
+    pub fn ctrl0(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl0> {
+        self.reg()
+    }
+
+    /// Access the port's `Ctrl1` register.
+    pub fn ctrl1(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl1> {
+        self.reg()
+    }
+
+    /// Access the port's `Ctrl2` register.
+    pub fn ctrl2(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl2> {
+        self.reg()
+    }
+
+    /// Access the port's `Ctrl3` register.
+    pub fn ctrl3(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl3> {
+        self.reg()
+    }
+
+    /// Access the port's `Ctrl4` register.
+    pub fn ctrl4(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl4> {
+        self.reg()
+    }
+
+    /// Access the port's `Ctrl5` register.
+    pub fn ctrl5(&mut self) -> PortReg<'_, 'smi, T, Port1Ctrl5> {
+        self.reg()
+    }
+
+    /// Access the port's queue 0 ingress rate limit register.
+    ///
+    /// Note that on port 3, bit 7 holds `Port3Q0IngressRateLimit::rmii_refclk_invert`.
+    pub fn q0_ingress_rate_limit(&mut self) -> PortReg<'_, 'smi, T, Port1Q0IngressRateLimit> {
+        self.reg()
+    }
+
+    /// Access the port's queue 1 ingress rate limit register.
+    pub fn q1_ingress_rate_limit(&mut self) -> PortReg<'_, 'smi, T, Port1Q1IngressRateLimit> {
+        self.reg()
+    }
+
+    /// Access the port's queue 2 ingress rate limit register.
+    pub fn q2_ingress_rate_limit(&mut self) -> PortReg<'_, 'smi, T, Port1Q2IngressRateLimit> {
+        self.reg()
+    }
+
+    /// Access the port's queue 3 ingress rate limit register.
+    pub fn q3_ingress_rate_limit(&mut self) -> PortReg<'_, 'smi, T, Port1Q3IngressRateLimit> {
+        self.reg()
+    }
+
+    /// Access the port's PHY special control/status register.
+    ///
+    /// Returns `Err` for port 3.
+    pub fn phy_special(
+        &mut self,
+    ) -> Result<PortReg<'_, 'smi, T, Port1PhySpecial>, UnsupportedRegister> {
+        self.phy_port_reg()
+    }
+
+    /// Access the port's LinkMD result register.
+    ///
+    /// Returns `Err` for port 3.
+    pub fn link_md_result(
+        &mut self,
+    ) -> Result<PortReg<'_, 'smi, T, Port1LinkMdResult>, UnsupportedRegister> {
+        self.phy_port_reg()
+    }
+
+    /// Access the port's `Ctrl12` register.
+    ///
+    /// Returns `Err` for port 3.
+    pub fn ctrl12(&mut self) -> Result<PortReg<'_, 'smi, T, Port1Ctrl12>, UnsupportedRegister> {
+        self.phy_port_reg()
+    }
+
+    /// Access the port's `Ctrl13` register.
+    ///
+    /// Returns `Err` for port 3.
+    pub fn ctrl13(&mut self) -> Result<PortReg<'_, 'smi, T, Port1Ctrl13>, UnsupportedRegister> {
+        self.phy_port_reg()
+    }
+
+    /// Access the port's `Status0` register.
+    ///
+    /// Returns `Err` for port 3.
+    pub fn status0(&mut self) -> Result<PortReg<'_, 'smi, T, Port1Status0>, UnsupportedRegister> {
+        self.phy_port_reg()
+    }
+
+    /// Access the port's `Status1` register.
+    ///
+    /// Note that on port 3 only the flow control, speed and duplex fields are present.
+    pub fn status1(&mut self) -> PortReg<'_, 'smi, T, Port1Status1> {
+        self.reg()
+    }
+
+    /// Read a summary of the port's link state from its `Status0` and `Status1` registers.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3 as it has no `Status0` register.
+    pub fn link_status<E>(&mut self) -> Result<LinkStatus, PortError<E>>
+    where
+        T: Read<Error = E>,
+    {
+        let status0 = self.status0()?.read().map_err(PortError::Interface)?;
+        let status1 = self.status1().read().map_err(PortError::Interface)?;
+        Ok(link_status(&status0, &status1))
+    }
+
+    /// Restart auto-negotiation via `Ctrl13::restart_an` and poll until `Status0::an_done` is set.
+    ///
+    /// The status is polled at most `max_polls` times before returning `PortError::Timeout`. On
+    /// completion, returns the abilities advertised by the link partner via `Status0`.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn restart_an_and_wait<E>(
+        &mut self,
+        max_polls: usize,
+    ) -> Result<LinkAbilities, PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.ctrl13()?
+            .modify(|w| w.restart_an().set_bit())
+            .map_err(PortError::Interface)?;
+        for _ in 0..max_polls {
+            let status0 = self.status0()?.read().map_err(PortError::Interface)?;
+            let r = status0.read();
+            if r.an_done().bit_is_set() {
+                return Ok(LinkAbilities {
+                    pause: r.partner_flow_ctrl().bit(),
+                    full_duplex_100: r.partner_100_fd().bit(),
+                    half_duplex_100: r.partner_100_hd().bit(),
+                    full_duplex_10: r.partner_10_fd().bit(),
+                    half_duplex_10: r.partner_10_hd().bit(),
+                });
+            }
+        }
+        Err(PortError::Timeout)
+    }
+
+    /// Run the LinkMD cable diagnostic test and return the decoded result.
+    ///
+    /// This sets `PhySpecial::vct_en` and polls at most `max_polls` times until the bit
+    /// self-clears, indicating the test is complete. The 9-bit fault count is assembled from
+    /// `PhySpecial::vct_fault_count8` and `LinkMdResult::vct_fault_count7_0`. The datasheet
+    /// recommends disabling auto MDI/MDI-X via `Ctrl13::disable_auto_mdix` prior to running the
+    /// test.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn run_cable_diagnostics<E>(
+        &mut self,
+        max_polls: usize,
+    ) -> Result<CableDiagnostics, PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.phy_special()?
+            .modify(|w| w.vct_en().set_bit())
+            .map_err(PortError::Interface)?;
+        for _ in 0..max_polls {
+            let phy_special = self.phy_special()?.read().map_err(PortError::Interface)?;
+            let r = phy_special.read();
+            if r.vct_en().bit_is_clear() {
+                let link_md = self
+                    .link_md_result()?
+                    .read()
+                    .map_err(PortError::Interface)?;
+                let low = link_md.read().vct_fault_count7_0().bits();
+                let high = u8::from(r.vct_fault_count8().bit());
+                let result = r.vct_result().variant().unwrap_or(VctStatus::Failed);
+                let fault_count = u16::from_be_bytes([high, low]);
+                return Ok(CableDiagnostics::from_vct(result, fault_count));
+            }
+        }
+        Err(PortError::Timeout)
+    }
+
+    /// Enable or disable both transmission and reception via `Ctrl2::transmit` and
+    /// `Ctrl2::receive`.
+    ///
+    /// Both bits are at the same position on all three ports. Address learning is unaffected.
+    pub fn set_enabled<E>(&mut self, enabled: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.ctrl2()
+            .modify(|w| w.transmit().bit(enabled).receive().bit(enabled))
+    }
+
+    /// Whether or not both transmission and reception are enabled via `Ctrl2`.
+    pub fn is_enabled(&mut self) -> Result<bool, T::Error>
+    where
+        T: Read,
+    {
+        let ctrl2 = self.ctrl2().read()?;
+        let r = ctrl2.read();
+        Ok(r.transmit().bit_is_set() && r.receive().bit_is_set())
+    }
+
+    /// Whether or not a far-end fault has been detected, via `Status1::far_end_fault`.
+    ///
+    /// Far-end fault detection is only applicable in fiber mode. Returns
+    /// `PortError::UnsupportedRegister` for port 3.
+    pub fn far_end_fault<E>(&mut self) -> Result<bool, PortError<E>>
+    where
+        T: Read<Error = E>,
+    {
+        if let PortId::Port3 = self.id {
+            return Err(PortError::UnsupportedRegister);
+        }
+        let status1 = self.status1().read().map_err(PortError::Interface)?;
+        Ok(status1.read().far_end_fault().bit_is_set())
+    }
+
+    /// Enable or disable far-end fault detection via `Ctrl13::disable_far_end_fault`.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn set_far_end_fault_detection<E>(&mut self, enable: bool) -> Result<(), PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.ctrl13()?
+            .modify(|w| w.disable_far_end_fault().bit(!enable))
+            .map_err(PortError::Interface)
+    }
+
+    /// Power down or power up the port's PHY via `Ctrl13::power_down`.
+    ///
+    /// After powering up, the link may be re-established sooner by restarting auto-negotiation
+    /// via `restart_an_and_wait`.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn set_power_down<E>(&mut self, power_down: bool) -> Result<(), PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.ctrl13()?
+            .modify(|w| w.power_down().bit(power_down))
+            .map_err(PortError::Interface)
+    }
+
+    /// Disable auto-negotiation and force the given speed and duplex mode via `Ctrl12`.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn force_link<E>(&mut self, speed: Speed, duplex: Duplex) -> Result<(), PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.ctrl12()?
+            .modify(|w| {
+                w.an_enable()
+                    .clear_bit()
+                    .force_speed()
+                    .bit(speed == Speed::Mbps100)
+                    .force_duplex()
+                    .bit(duplex == Duplex::Full)
+            })
+            .map_err(PortError::Interface)
+    }
+
+    /// Select the given loopback mode via `Ctrl13::loopback` and `PhySpecial::remote_loopback`.
+    ///
+    /// Returns `PortError::UnsupportedRegister` for port 3.
+    pub fn set_loopback<E>(&mut self, mode: LoopbackMode) -> Result<(), PortError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let far_end = mode == LoopbackMode::FarEnd;
+        let remote = mode == LoopbackMode::Remote;
+        self.ctrl13()?
+            .modify(|w| w.loopback().bit(far_end))
+            .map_err(PortError::Interface)?;
+        self.phy_special()?
+            .modify(|w| w.remote_loopback().bit(remote))
+            .map_err(PortError::Interface)
+    }
+
+    /// Read the 16-bit default tag from `PortNCtrl3` (bits `15:8`) and `PortNCtrl4` (bits `7:0`).
+    pub fn default_tag(&mut self) -> Result<u16, T::Error>
+    where
+        T: Read,
+    {
+        let high = self.smi.0.read(self.addr(Address::Port1Ctrl3))?;
+        let low = self.smi.0.read(self.addr(Address::Port1Ctrl4))?;
+        Ok(u16::from_be_bytes([high, low]))
+    }
+
+    /// Write the 16-bit default tag to `PortNCtrl3` (bits `15:8`) and `PortNCtrl4` (bits `7:0`).
+    pub fn set_default_tag(&mut self, tag: u16) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        let [high, low] = tag.to_be_bytes();
+        self.smi.0.write(self.addr(Address::Port1Ctrl3), high)?;
+        self.smi.0.write(self.addr(Address::Port1Ctrl4), low)
+    }
+
+    /// The address of the equivalent of the given port 1 register for this port.
+    fn addr(&self, port1_addr: Address) -> u8 {
+        let addr: u8 = port1_addr.into();
+        addr + Self::ADDR_STRIDE * self.id.index() as u8
+    }
+}
+
+impl<'port, 'smi, T, R> PortReg<'port, 'smi, T, R>
+where
+    R: Register,
+{
+    /// Read the value from this port's equivalent of register `R` via SMI.
+    pub fn read(&mut self) -> Result<R, T::Error>
+    where
+        T: Read,
+    {
+        let addr = self.port.addr(R::ADDRESS);
+        let bits = self.port.smi.0.read(addr)?;
+        Ok(R::from(bits))
+    }
+
+    /// Write to this port's equivalent of register `R`, initialised with a default state.
+    ///
+    /// As with `Reg::write`, untouched fields are written with their default values.
+    pub fn write<F>(&mut self, write: F) -> Result<(), T::Error>
+    where
+        T: Write,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        self.write_from(R::default(), write)
+    }
+
+    /// Write to this port's equivalent of register `R`, initialised with the given state.
+    pub fn write_from<F>(&mut self, mut reg: R, write: F) -> Result<(), T::Error>
+    where
+        T: Write,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        write(&mut W(&mut reg));
+        let addr = self.port.addr(R::ADDRESS);
+        self.port.smi.0.write(addr, reg.into())
+    }
+
+    /// Modify this port's equivalent of register `R`.
+    ///
+    /// This first reads the value from the register, delivers it to the user via the `modify`
+    /// function, and then writes the result.
+    pub fn modify<F, E>(&mut self, modify: F) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let mut reg: R = self.read()?;
+        modify(&mut W(&mut reg));
+        let addr = self.port.addr(R::ADDRESS);
+        self.port.smi.0.write(addr, reg.into())
+    }
+
+    /// Modify this port's equivalent of register `R`, only writing the result if it differs from
+    /// the value read.
+    ///
+    /// Returns whether or not the write occurred.
+    pub fn modify_if_changed<F, E>(&mut self, modify: F) -> Result<bool, E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+        R: Copy + PartialEq,
+        F: for<'a, 'b> FnOnce(&'a mut W<&'b mut R>) -> &'a mut W<&'b mut R>,
+    {
+        let read: R = self.read()?;
+        let mut reg = read;
+        modify(&mut W(&mut reg));
+        if reg == read {
+            return Ok(false);
+        }
+        let addr = self.port.addr(R::ADDRESS);
+        self.port.smi.0.write(addr, reg.into())?;
+        Ok(true)
+    }
+}
+
+/// Decode the link state from a port's `Status0` and `Status1` registers.
+pub(super) fn link_status(status0: &Port1Status0, status1: &Port1Status1) -> LinkStatus {
+    let (speed, duplex, flow_control) = status1_mode(status1);
+    let r0 = status0.read();
+    LinkStatus {
+        up: r0.link_good().bit(),
+        speed,
+        duplex,
+        an_done: r0.an_done().bit(),
+        flow_control,
+    }
+}
+
+/// Decode the operating speed, duplex mode and flow control from a port's `Status1` register.
+pub(super) fn status1_mode(status1: &Port1Status1) -> (Speed, Duplex, FlowControl) {
+    let r1 = status1.read();
+    let speed = match r1.operation_speed().bit() {
+        true => Speed::Mbps100,
+        false => Speed::Mbps10,
+    };
+    let duplex = match r1.operation_duplex().bit() {
+        true => Duplex::Full,
+        false => Duplex::Half,
+    };
+    let flow_control = FlowControl {
+        tx: r1.tx_flow_ctrl().bit(),
+        rx: r1.rx_flow_ctrl().bit(),
+    };
+    (speed, duplex, flow_control)
+}
+
+impl From<u16> for VlanTag {
+    fn from(tag: u16) -> Self {
+        VlanTag {
+            priority: (tag >> 13) as u8,
+            cfi: tag & (1 << 12) != 0,
+            vid: tag & 0xFFF,
+        }
+    }
+}
+
+impl From<VlanTag> for u16 {
+    fn from(tag: VlanTag) -> Self {
+        (u16::from(tag.priority & 0b111) << 13) | (u16::from(tag.cfi) << 12) | (tag.vid & 0xFFF)
+    }
+}
+
+impl From<u8> for PortSet {
+    /// Bits beyond bit `2` are ignored.
+    fn from(bits: u8) -> Self {
+        PortSet(bits & Self::ALL.0)
+    }
+}
+
+impl From<PortSet> for u8 {
+    fn from(set: PortSet) -> Self {
+        set.0
+    }
+}
+
+impl From<PortId> for PortSet {
+    fn from(id: PortId) -> Self {
+        Self::single(id)
+    }
+}
+
+impl<const N: usize> From<[PortId; N]> for PortSet {
+    fn from(ids: [PortId; N]) -> Self {
+        ids.iter().cloned().collect()
+    }
+}
+
+impl core::iter::FromIterator<PortId> for PortSet {
+    fn from_iter<I>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = PortId>,
+    {
+        let mut set = Self::EMPTY;
+        for id in ids {
+            set.insert(id);
+        }
+        set
+    }
+}
+
+impl IntoIterator for PortSet {
+    type Item = PortId;
+    type IntoIter = PortSetIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Iterator for PortSetIter {
+    type Item = PortId;
+    fn next(&mut self) -> Option<Self::Item> {
+        let set = &mut self.0;
+        let id = PortId::ALL.iter().cloned().find(|&id| set.contains(id))?;
+        set.remove(id);
+        Some(id)
+    }
+}
+
+impl core::ops::BitOr for PortSet {
+    type Output = Self;
+    /// The union of both sets.
+    fn bitor(self, other: Self) -> Self {
+        PortSet(self.0 | other.0)
+    }
+}
+
+impl core::ops::BitOrAssign for PortSet {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other;
+    }
+}
+
+impl core::ops::BitAnd for PortSet {
+    type Output = Self;
+    /// The intersection of both sets.
+    fn bitand(self, other: Self) -> Self {
+        PortSet(self.0 & other.0)
+    }
+}
+
+impl core::ops::BitAndAssign for PortSet {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl core::ops::Not for PortSet {
+    type Output = Self;
+    /// The set of all ports not within this set.
+    fn not(self) -> Self {
+        PortSet(!self.0 & Self::ALL.0)
+    }
+}
+
+impl<E> From<UnsupportedRegister> for PortError<E> {
+    fn from(_: UnsupportedRegister) -> Self {
+        PortError::UnsupportedRegister
+    }
+}
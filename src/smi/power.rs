@@ -0,0 +1,65 @@
+//! Power management configuration.
+
+use super::{PwrMgmtMode, Read, Smi, Write};
+
+/// The unit of the sleep timer held by `SleepMode::data`, in milliseconds.
+pub const SLEEP_TIMER_UNIT_MS: u32 = 20;
+
+impl<T> Smi<T> {
+    /// Read the sleep timer from `SleepMode`.
+    ///
+    /// In energy detect mode, this is the period for which no energy must be detected before the
+    /// device enters the low power state, in units of `SLEEP_TIMER_UNIT_MS`. The default of
+    /// `0x50` is 1.6 seconds.
+    pub fn sleep_timer(&mut self) -> Result<u8, T::Error>
+    where
+        T: Read,
+    {
+        Ok(self.sleep_mode().read()?.read().data().bits())
+    }
+
+    /// Write the sleep timer to `SleepMode`, in units of `SLEEP_TIMER_UNIT_MS`.
+    pub fn set_sleep_timer(&mut self, timer: u8) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.sleep_mode().write(|w| w.data().bits(timer))
+    }
+
+    /// Enter energy detect mode via `PwrMgmtAndLedMode::pwr_mgmt_mode` with the given sleep timer.
+    ///
+    /// The sleep timer is written first so that it is in effect as soon as the mode changes. All
+    /// other fields of `PwrMgmtAndLedMode` are preserved.
+    pub fn set_energy_detect_mode<E>(&mut self, sleep_timer: u8) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.set_sleep_timer(sleep_timer)?;
+        self.pwr_mgmt_and_led_mode()
+            .modify(|w| w.pwr_mgmt_mode().variant(PwrMgmtMode::EnergyDetect))
+    }
+
+    /// Whether or not the internal LDO is enabled, i.e. `InternalLdoCtrl::disable` is clear.
+    pub fn internal_ldo(&mut self) -> Result<bool, T::Error>
+    where
+        T: Read,
+    {
+        Ok(self
+            .internal_ldo_ctrl()
+            .read()?
+            .read()
+            .disable()
+            .bit_is_clear())
+    }
+
+    /// Enable or disable the internal LDO via `InternalLdoCtrl::disable`.
+    ///
+    /// The LDO should only be disabled when the core voltage is supplied externally.
+    pub fn set_internal_ldo<E>(&mut self, enabled: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.internal_ldo_ctrl()
+            .modify(|w| w.disable().bit(!enabled))
+    }
+}
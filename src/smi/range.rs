@@ -0,0 +1,71 @@
+//! Access to runs of consecutive registers.
+
+use super::{Address, Read, Smi, Write};
+use core::convert::TryFrom;
+
+impl<T> Smi<T> {
+    /// Read the run of consecutive registers starting at `start` into `out`.
+    ///
+    /// The number of registers read is `out.len()`. Returns `Error::InvalidAddress` without
+    /// performing any reads if the run includes an address at which there is no register.
+    pub fn read_range<E>(&mut self, start: Address, out: &mut [u8]) -> Result<(), crate::Error<E>>
+    where
+        T: Read<Error = E>,
+    {
+        check_range(start, out.len())?;
+        self.read_consecutive(start, out)
+            .map_err(crate::Error::Interface)
+    }
+
+    /// Write the given data to the run of consecutive registers starting at `start`.
+    ///
+    /// The number of registers written is `data.len()`. Returns `Error::InvalidAddress`
+    /// without performing any writes if the run includes an address at which there is no
+    /// register.
+    pub fn write_range<E>(&mut self, start: Address, data: &[u8]) -> Result<(), crate::Error<E>>
+    where
+        T: Write<Error = E>,
+    {
+        check_range(start, data.len())?;
+        self.write_consecutive(start, data)
+            .map_err(crate::Error::Interface)
+    }
+
+    /// Read consecutive registers starting at `start` without checking the addresses.
+    pub(crate) fn read_consecutive(
+        &mut self,
+        start: Address,
+        out: &mut [u8],
+    ) -> Result<(), T::Error>
+    where
+        T: Read,
+    {
+        let start: u8 = start.into();
+        for (addr, byte) in (start..).zip(out.iter_mut()) {
+            *byte = self.0.read(addr)?;
+        }
+        Ok(())
+    }
+
+    /// Write consecutive registers starting at `start` without checking the addresses.
+    pub(crate) fn write_consecutive(&mut self, start: Address, data: &[u8]) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        let start: u8 = start.into();
+        for (addr, &byte) in (start..).zip(data.iter()) {
+            self.0.write(addr, byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that there is a register at each of the `count` addresses starting at `start`.
+fn check_range<E>(start: Address, count: usize) -> Result<(), crate::Error<E>> {
+    let start = usize::from(u8::from(start));
+    for addr in start..start + count {
+        let addr = u8::try_from(addr).map_err(|_| crate::Error::InvalidAddress)?;
+        Address::try_from(addr).map_err(|_| crate::Error::InvalidAddress)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! Conversions between data rates and the 7-bit codes held by the ingress and egress rate limit
+//! registers.
+//!
+//! | Code         | Rate                                   |
+//! | ------------ | -------------------------------------- |
+//! | `0`          | Unlimited (100Mbps, or 10Mbps at 10BT) |
+//! | `1..=100`    | `code` Mbps                            |
+//! | `101..=115`  | `(code - 100) * 64` Kbps               |
+//! | `116..=127`  | Reserved                               |
+//!
+//! When a port operates at 10Mbps, codes `11..=100` are limited to 10Mbps.
+
+use super::{Port, PortId, Queue, Read, Write};
+use core::convert::TryFrom;
+
+/// A rate limit, as held by the `limit` field of the rate limit registers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct RateLimit(u8);
+
+impl RateLimit {
+    /// No rate limit.
+    pub const UNLIMITED: Self = RateLimit(0);
+    /// The rate in Kbps of an unlimited 100BT port.
+    const UNLIMITED_KBPS: u32 = 100_000;
+    /// The highest code in Mbps.
+    const MAX_MBPS_CODE: u8 = 100;
+    /// The highest code in 64Kbps steps.
+    const MAX_KBPS_CODE: u8 = 115;
+    /// The step between codes below 1Mbps.
+    const KBPS_STEP: u32 = 64;
+
+    /// The nearest rate limit not exceeding `kbps`.
+    ///
+    /// Rates of 100Mbps or more produce `UNLIMITED`. Rates below the minimum of 64Kbps are rounded
+    /// up to 64Kbps.
+    pub fn from_kbps(kbps: u32) -> Self {
+        if kbps >= Self::UNLIMITED_KBPS {
+            Self::UNLIMITED
+        } else if kbps >= 1_000 {
+            RateLimit((kbps / 1_000) as u8)
+        } else {
+            let steps = (kbps / Self::KBPS_STEP).max(1);
+            RateLimit(Self::MAX_MBPS_CODE + steps as u8)
+        }
+    }
+
+    /// The rate in Kbps, where `UNLIMITED` is reported as 100Mbps.
+    pub fn to_kbps(self) -> u32 {
+        match self.0 {
+            0 => Self::UNLIMITED_KBPS,
+            code if code <= Self::MAX_MBPS_CODE => u32::from(code) * 1_000,
+            code => u32::from(code - Self::MAX_MBPS_CODE) * Self::KBPS_STEP,
+        }
+    }
+
+    /// The raw 7-bit code.
+    pub fn code(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for RateLimit {
+    type Error = crate::InvalidValue;
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        if code <= Self::MAX_KBPS_CODE {
+            Ok(RateLimit(code))
+        } else {
+            Err(crate::InvalidValue)
+        }
+    }
+}
+
+impl From<RateLimit> for u8 {
+    fn from(limit: RateLimit) -> Self {
+        limit.0
+    }
+}
+
+impl<'smi, T> Port<'smi, T> {
+    /// Write the ingress rate limit of the given priority queue via the queue's
+    /// `IngressRateLimit::limit` field.
+    ///
+    /// All other bits of the register are preserved.
+    pub fn set_ingress_rate_limit<E>(&mut self, queue: Queue, limit: RateLimit) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let code = limit.code();
+        match queue {
+            Queue::Q0 => self
+                .q0_ingress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            Queue::Q1 => self
+                .q1_ingress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            Queue::Q2 => self
+                .q2_ingress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            Queue::Q3 => self
+                .q3_ingress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+        }
+    }
+
+    /// Write the egress rate limit of the given priority queue via the queue's
+    /// `EgressRateLimit::limit` field.
+    ///
+    /// All other bits of the register are preserved.
+    pub fn set_egress_rate_limit<E>(&mut self, queue: Queue, limit: RateLimit) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        let code = limit.code();
+        match (self.id, queue) {
+            (PortId::Port1, Queue::Q0) => self
+                .smi
+                .port1_q0_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port1, Queue::Q1) => self
+                .smi
+                .port1_q1_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port1, Queue::Q2) => self
+                .smi
+                .port1_q2_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port1, Queue::Q3) => self
+                .smi
+                .port1_q3_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port2, Queue::Q0) => self
+                .smi
+                .port2_q0_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port2, Queue::Q1) => self
+                .smi
+                .port2_q1_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port2, Queue::Q2) => self
+                .smi
+                .port2_q2_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port2, Queue::Q3) => self
+                .smi
+                .port2_q3_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port3, Queue::Q0) => self
+                .smi
+                .port3_q0_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port3, Queue::Q1) => self
+                .smi
+                .port3_q1_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port3, Queue::Q2) => self
+                .smi
+                .port3_q2_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+            (PortId::Port3, Queue::Q3) => self
+                .smi
+                .port3_q3_egress_rate_limit()
+                .modify(|w| w.limit().bits(code)),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! An adapter that retries failed SMI transactions.
+
+use super::{Read, Write};
+
+/// Wraps an `smi::Read` and/or `smi::Write` implementation, retrying each failed read or write.
+///
+/// Each transaction is attempted at most `retries + 1` times. The error of the final attempt is
+/// returned if all attempts fail.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Retry<T> {
+    inner: T,
+    retries: usize,
+}
+
+impl<T> Retry<T> {
+    /// Wrap the given interface, retrying each failed transaction up to `retries` times.
+    pub fn new(inner: T, retries: usize) -> Self {
+        Retry { inner, retries }
+    }
+
+    /// The number of times a failed transaction is retried.
+    pub fn retries(&self) -> usize {
+        self.retries
+    }
+
+    /// Access the wrapped interface.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped interface.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner interface.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for Retry<T> {
+    type Error = T::Error;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        let mut result = self.inner.read(reg_addr);
+        for _ in 0..self.retries {
+            if result.is_ok() {
+                break;
+            }
+            result = self.inner.read(reg_addr);
+        }
+        result
+    }
+}
+
+impl<T: Write> Write for Retry<T> {
+    type Error = T::Error;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        let mut result = self.inner.write(reg_addr, data);
+        for _ in 0..self.retries {
+            if result.is_ok() {
+                break;
+            }
+            result = self.inner.write(reg_addr, data);
+        }
+        result
+    }
+}
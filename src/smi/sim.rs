@@ -0,0 +1,108 @@
+//! A simulated SMI interface that models some of the switch's active register behaviour.
+
+use super::{Address, Map, Read, State, Write};
+use core::convert::TryFrom;
+
+/// A `Map`-backed SMI interface that models the following register behaviour:
+///
+/// - Setting `Reset::software` resets every register to its default state, after which the bit
+///   reads as clear. Setting `Reset::pcs` clears the bit.
+/// - Setting `Gc0::flush_dynamic_mac_table` or `Gc0::flush_static_mac_table` completes the flush
+///   immediately, clearing the bit.
+/// - Setting `Ctrl13::restart_an` on port 1 or 2 completes auto-negotiation immediately, clearing
+///   the bit and setting `Status0::an_done` if `Ctrl12::an_enable` is set.
+/// - Setting `PhySpecial::vct_en` on port 1 or 2 completes the cable diagnostic test immediately,
+///   clearing the bit.
+///
+/// All other writes behave as they do for `Map`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sim {
+    map: Map,
+}
+
+impl Sim {
+    /// Simulate a switch with the given initial register state.
+    pub fn new(map: Map) -> Self {
+        Sim { map }
+    }
+
+    /// The current register state.
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    /// Mutable access to the register state, e.g. to simulate link changes.
+    pub fn map_mut(&mut self) -> &mut Map {
+        &mut self.map
+    }
+
+    /// Unwrap the register state.
+    pub fn into_map(self) -> Map {
+        self.map
+    }
+
+    fn bits(&self, addr: Address) -> u8 {
+        (*self.map.state(addr)).into()
+    }
+
+    fn set_bits(&mut self, addr: Address, bits: u8) {
+        self.map.set_state(State::from_addr_and_data(addr, bits));
+    }
+
+    fn clear_bits(&mut self, addr: Address, mask: u8) {
+        let bits = self.bits(addr);
+        self.set_bits(addr, bits & !mask);
+    }
+
+    /// Complete auto-negotiation if `Ctrl13::restart_an` was set.
+    fn restart_an(&mut self, ctrl13: Address, ctrl12: Address, status0: Address) {
+        const RESTART_AN: u8 = 1 << 5;
+        const AN_ENABLE: u8 = 1 << 7;
+        const AN_DONE: u8 = 1 << 6;
+        if self.bits(ctrl13) & RESTART_AN == 0 {
+            return;
+        }
+        self.clear_bits(ctrl13, RESTART_AN);
+        if self.bits(ctrl12) & AN_ENABLE != 0 {
+            let bits = self.bits(status0);
+            self.set_bits(status0, bits | AN_DONE);
+        }
+    }
+
+    /// Apply the modelled behaviour following a write to the given register.
+    fn written(&mut self, addr: Address) {
+        const SOFTWARE_RESET: u8 = 1 << 4;
+        const PCS_RESET: u8 = 1 << 0;
+        const FLUSH: u8 = 0b0011_0000;
+        const VCT_EN: u8 = 1 << 4;
+        match addr {
+            Address::Reset if self.bits(addr) & SOFTWARE_RESET != 0 => self.map = Map::default(),
+            Address::Reset => self.clear_bits(addr, PCS_RESET),
+            Address::Gc0 => self.clear_bits(addr, FLUSH),
+            Address::Port1Ctrl13 => {
+                self.restart_an(addr, Address::Port1Ctrl12, Address::Port1Status0)
+            }
+            Address::Port2Ctrl13 => {
+                self.restart_an(addr, Address::Port2Ctrl12, Address::Port2Status0)
+            }
+            Address::Port1PhySpecial | Address::Port2PhySpecial => self.clear_bits(addr, VCT_EN),
+            _ => (),
+        }
+    }
+}
+
+impl Read for Sim {
+    type Error = crate::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.map.read(reg_addr)
+    }
+}
+
+impl Write for Sim {
+    type Error = crate::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.map.write(reg_addr, data)?;
+        self.written(Address::try_from(reg_addr)?);
+        Ok(())
+    }
+}
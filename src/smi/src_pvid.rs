@@ -0,0 +1,92 @@
+//! Typed access to the `InsertSrcPvid` register.
+
+use super::{InsertSrcPvid, PortId, Read, Smi, Write};
+
+/// For each pair of ports, whether the source port's PVID is inserted into untagged frames that
+/// egress at the other port, as held by `InsertSrcPvid`.
+///
+/// Indexed by `(from, at)`, where `from` is the ingress port whose PVID is inserted and `at` is
+/// the egress port. There is no bit for a port to itself, so such pairs are always `false`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct InsertSrcPvidMatrix(u8);
+
+impl InsertSrcPvidMatrix {
+    /// The matrix with no insertion enabled.
+    pub const NONE: Self = InsertSrcPvidMatrix(0);
+
+    /// The raw value of the `InsertSrcPvid` register.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Whether or not the PVID of `from` is inserted into untagged frames egressing at `at`.
+    pub fn get(self, from: PortId, at: PortId) -> bool {
+        match bit(from, at) {
+            Some(bit) => self.0 & bit != 0,
+            None => false,
+        }
+    }
+
+    /// Enable or disable inserting the PVID of `from` into untagged frames egressing at `at`.
+    ///
+    /// Has no effect if `from` and `at` are the same port.
+    pub fn set(&mut self, from: PortId, at: PortId, enable: bool) {
+        if let Some(bit) = bit(from, at) {
+            if enable {
+                self.0 |= bit;
+            } else {
+                self.0 &= !bit;
+            }
+        }
+    }
+}
+
+impl From<InsertSrcPvid> for InsertSrcPvidMatrix {
+    fn from(reg: InsertSrcPvid) -> Self {
+        let bits: u8 = reg.into();
+        InsertSrcPvidMatrix(bits & 0b0011_1111)
+    }
+}
+
+impl From<InsertSrcPvidMatrix> for InsertSrcPvid {
+    fn from(matrix: InsertSrcPvidMatrix) -> Self {
+        InsertSrcPvid::from(matrix.0)
+    }
+}
+
+impl<T> Smi<T> {
+    /// Read the insert-source-PVID matrix from `InsertSrcPvid`.
+    pub fn insert_src_pvid_matrix(&mut self) -> Result<InsertSrcPvidMatrix, T::Error>
+    where
+        T: Read,
+    {
+        self.insert_src_pvid().read().map(InsertSrcPvidMatrix::from)
+    }
+
+    /// Write the given insert-source-PVID matrix to `InsertSrcPvid`.
+    pub fn set_insert_src_pvid_matrix(
+        &mut self,
+        matrix: InsertSrcPvidMatrix,
+    ) -> Result<(), T::Error>
+    where
+        T: Write,
+    {
+        self.insert_src_pvid().write(|w| w.bits(matrix.0))
+    }
+}
+
+/// The bit within `InsertSrcPvid` for the given pair of ports, if any.
+fn bit(from: PortId, at: PortId) -> Option<u8> {
+    let ix = match (from, at) {
+        (PortId::Port1, PortId::Port2) => 5,
+        (PortId::Port1, PortId::Port3) => 4,
+        (PortId::Port2, PortId::Port1) => 3,
+        (PortId::Port2, PortId::Port3) => 2,
+        (PortId::Port3, PortId::Port1) => 1,
+        (PortId::Port3, PortId::Port2) => 0,
+        _ => return None,
+    };
+    Some(1 << ix)
+}
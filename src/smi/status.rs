@@ -0,0 +1,71 @@
+//! A compact summary of the link state of every port.
+
+use super::port;
+use super::{Port1Status0, Port1Status1, PortId, Read, Smi};
+use crate::link::{Duplex, FlowControl, LinkStatus, Speed};
+
+/// The link state of every port, as read by `Smi::read_status`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct SwitchStatus {
+    /// The link state of port 1.
+    pub port1: LinkStatus,
+    /// The link state of port 2.
+    pub port2: LinkStatus,
+    /// The state of port 3, the MAC port.
+    pub port3: MacStatus,
+}
+
+/// The state of port 3 as read from `Port3Status1`.
+///
+/// Port 3 is the MAC port and has no `Status0` register, so its link and auto-negotiation state
+/// are unavailable.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct MacStatus {
+    /// The operating speed.
+    pub speed: Speed,
+    /// The operating duplex mode.
+    pub duplex: Duplex,
+    /// The active flow control directions.
+    pub flow_control: FlowControl,
+}
+
+impl<T> Smi<T> {
+    /// Read the link state of every port using a minimal, fixed set of reads.
+    ///
+    /// Only `Port1Status0`, `Port1Status1`, `Port2Status0`, `Port2Status1` and `Port3Status1` are
+    /// read, making this suitable for cheaply polling the link state.
+    pub fn read_status(&mut self) -> Result<SwitchStatus, T::Error>
+    where
+        T: Read,
+    {
+        let port1 = self.phy_port_link_status(PortId::Port1)?;
+        let port2 = self.phy_port_link_status(PortId::Port2)?;
+        let status1 = self.port(PortId::Port3).status1().read()?;
+        let (speed, duplex, flow_control) = port::status1_mode(&status1);
+        let port3 = MacStatus {
+            speed,
+            duplex,
+            flow_control,
+        };
+        Ok(SwitchStatus {
+            port1,
+            port2,
+            port3,
+        })
+    }
+
+    /// Read the link state of port 1 or 2.
+    fn phy_port_link_status(&mut self, id: PortId) -> Result<LinkStatus, T::Error>
+    where
+        T: Read,
+    {
+        let mut port = self.port(id);
+        let status0 = port.reg::<Port1Status0>().read()?;
+        let status1 = port.reg::<Port1Status1>().read()?;
+        Ok(port::link_status(&status0, &status1))
+    }
+}
@@ -0,0 +1,49 @@
+//! Encoding and decoding of the tail tag byte used on port 3 when tail tagging is enabled.
+//!
+//! When `Gc1::port3_tail_tag` is set, the host appends a single tail tag byte to each frame sent
+//! to port 3, and the switch appends one to each frame it forwards to port 3.
+//!
+//! | Direction        | Bits  | Meaning                                                         |
+//! | ---------------- | ----- | --------------------------------------------------------------- |
+//! | Host to switch   | `1:0` | Destination ports, bit `0` for port 1 and bit `1` for port 2    |
+//! | Switch to host   | `0`   | Source port, `0` for port 1 and `1` for port 2                  |
+//!
+//! A host to switch tag of `0` forwards the frame according to the normal address lookup.
+
+use super::{Gc1, PortId, PortSet, Read, Smi, Write};
+
+/// The information carried by a tail tag appended by the switch to a frame forwarded to port 3.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct TailTagInfo {
+    /// The port on which the frame was received.
+    pub source: PortId,
+}
+
+/// Encode the tail tag to append to a frame sent from the host to port 3.
+///
+/// Port 3 is ignored as a destination. An empty set produces `0`, requesting the normal address
+/// lookup.
+pub fn encode_tail_tag(dst_ports: PortSet) -> u8 {
+    dst_ports.bits() & 0b11
+}
+
+/// Decode the tail tag appended to a frame forwarded from the switch to port 3.
+pub fn decode_tail_tag(byte: u8) -> TailTagInfo {
+    let source = match byte & 0b1 {
+        0 => PortId::Port1,
+        _ => PortId::Port2,
+    };
+    TailTagInfo { source }
+}
+
+impl<T> Smi<T> {
+    /// Enable or disable tail tagging on port 3 via `Gc1::port3_tail_tag`.
+    pub fn set_tail_tag<E>(&mut self, enable: bool) -> Result<(), E>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        self.reg::<Gc1>().modify(|w| w.port3_tail_tag().bit(enable))
+    }
+}
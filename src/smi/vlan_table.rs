@@ -0,0 +1,95 @@
+//! High-level access to the VLAN table.
+
+use super::indirect::{self, IndirectError, Table};
+use super::{Read, Smi, Write};
+
+/// Provides access to the VLAN table via the indirect access registers.
+///
+/// The VLAN table is only used for forwarding when `Gc3::vlan` is enabled.
+pub struct VlanTable<'smi, T> {
+    pub smi: &'smi mut Smi<T>,
+}
+
+/// A single entry within the VLAN table.
+///
+/// Each entry occupies 20 bits of the indirect data registers, laid out as follows:
+///
+/// | Bits     | Field        |
+/// | -------- | ------------ |
+/// | `19`     | Valid        |
+/// | `18:16`  | Membership   |
+/// | `15:12`  | FID          |
+/// | `11:0`   | VID          |
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct VlanEntry {
+    /// Whether or not the entry is valid.
+    pub valid: bool,
+    /// The ports that are members of the VLAN, where bit `n` represents port `n + 1`.
+    pub membership: u8,
+    /// The filter ID, used for address lookup.
+    pub fid: u8,
+    /// The 12-bit IEEE 802.1Q VLAN ID.
+    pub vid: u16,
+}
+
+impl<T> Smi<T> {
+    /// Access the VLAN table.
+    pub fn vlan_table(&mut self) -> VlanTable<'_, T> {
+        VlanTable { smi: self }
+    }
+}
+
+impl<'smi, T> VlanTable<'smi, T> {
+    /// The number of entries in the VLAN table.
+    pub const LEN: u8 = 16;
+
+    /// Read the VLAN table entry at the given index.
+    pub fn read<E>(&mut self, entry: u8) -> Result<VlanEntry, IndirectError<E>>
+    where
+        T: Read<Error = E> + Write<Error = E>,
+    {
+        check_entry(entry)?;
+        let data = self.smi.indirect().read(Table::Vlan, entry.into())?;
+        Ok(VlanEntry::from(indirect::data_to_u64(&data)))
+    }
+
+    /// Write the given VLAN table entry to the given index.
+    pub fn write(&mut self, entry: u8, v: &VlanEntry) -> Result<(), IndirectError<T::Error>>
+    where
+        T: Write,
+    {
+        check_entry(entry)?;
+        let data = indirect::u64_to_data((*v).into());
+        self.smi.indirect().write(Table::Vlan, entry.into(), &data)
+    }
+}
+
+impl From<u64> for VlanEntry {
+    fn from(data: u64) -> Self {
+        VlanEntry {
+            valid: data & (1 << 19) != 0,
+            membership: ((data >> 16) & 0b111) as u8,
+            fid: ((data >> 12) & 0xF) as u8,
+            vid: (data & 0xFFF) as u16,
+        }
+    }
+}
+
+impl From<VlanEntry> for u64 {
+    fn from(entry: VlanEntry) -> Self {
+        (u64::from(entry.valid) << 19)
+            | (u64::from(entry.membership & 0b111) << 16)
+            | (u64::from(entry.fid & 0xF) << 12)
+            | u64::from(entry.vid & 0xFFF)
+    }
+}
+
+/// Ensure the given entry index lies within the table.
+fn check_entry<E>(entry: u8) -> Result<(), IndirectError<E>> {
+    if entry >= VlanTable::<()>::LEN {
+        return Err(IndirectError::EntryOutOfRange);
+    }
+    Ok(())
+}
@@ -7,6 +7,12 @@ fn test_bcr_default() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_bcr_reset_value() {
+    use miim::Register;
+    assert_eq!(miim::Bcr::reset_value(), 0b0001_0000_0010_0000);
+}
+
 #[test]
 fn test_bcr_reset() {
     // All fields are RW, so resetting should reset to default state.
@@ -68,3 +74,594 @@ fn miim_api() {
     // Check non-lexical borrows are working nicely.
     assert_eq!(a, miim.phy(0).bcr().read().unwrap());
 }
+
+#[cfg(feature = "async")]
+#[test]
+fn miim_async_api() {
+    use ksz8863::miim::{AsyncRead, AsyncWrite};
+    use mdio::miim::{Read, Write};
+
+    // A mock asynchronous interface backed by a `Map`.
+    struct AsyncMap(miim::Map);
+
+    impl AsyncRead for AsyncMap {
+        type Error = ksz8863::InvalidAddress;
+        async fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+            Read::read(&mut self.0, phy_addr, reg_addr)
+        }
+    }
+
+    impl AsyncWrite for AsyncMap {
+        type Error = ksz8863::InvalidAddress;
+        async fn write(
+            &mut self,
+            phy_addr: u8,
+            reg_addr: u8,
+            data: u16,
+        ) -> Result<(), Self::Error> {
+            Write::write(&mut self.0, phy_addr, reg_addr, data)
+        }
+    }
+
+    let mut miim = Miim(AsyncMap(miim::Map::default()));
+    futures::executor::block_on(async {
+        let mut phy = miim.phy(0);
+        let bcr = phy.bcr().read_async().await.unwrap();
+        assert_eq!(bcr, miim::Bcr::default());
+        phy.bcr()
+            .modify_async(|w| w.force_fd().set_bit())
+            .await
+            .unwrap();
+        let bcr = phy.bcr().read_async().await.unwrap();
+        assert!(bcr.read().force_fd().bit_is_set());
+        phy.bcr().write_async(|w| w.reset()).await.unwrap();
+        let state = phy.read_async(miim::Address::Bcr).await.unwrap();
+        assert_eq!(state, miim::State::Bcr(miim::Bcr::default()));
+    });
+}
+
+#[test]
+fn miim_map_diff() {
+    let a = miim::Map::default();
+    let mut b = a.clone();
+    assert_eq!(a.diff(&b).count(), 0);
+    b.bcr_mut().write().force_fd().set_bit();
+    b.anar_mut().write().adv_pause().clear_bit();
+    let addrs: Vec<_> = a.diff(&b).map(|(addr, _, _)| addr).collect();
+    assert_eq!(addrs, vec![miim::Address::Bcr, miim::Address::Anar]);
+}
+
+#[test]
+fn miim_map_iter() {
+    let mut map = miim::Map::default();
+    let states: Vec<_> = map.iter().collect();
+    assert_eq!(states.len(), miim::Map::LEN);
+    for (addr, mut state) in map.iter_mut() {
+        let default = miim::State::from_addr_default(addr);
+        assert_eq!(*state, default);
+        state.set(default).unwrap();
+    }
+    assert_eq!(map, miim::Map::default());
+}
+
+#[test]
+fn miim_write_map() {
+    let mut source = miim::Map::default();
+    source.bcr_mut().write().force_fd().set_bit();
+    source.anlpar_mut().write().bits(0xFFFF);
+
+    let mut miim = Miim(miim::Map::default());
+    miim.phy(0).write_map(&source).unwrap();
+    let device = miim.0;
+    assert_eq!(device.reg::<miim::Bcr>(), source.reg::<miim::Bcr>());
+    // Read-only registers are not written.
+    assert_eq!(device.reg::<miim::Anlpar>(), &miim::Anlpar::default());
+}
+
+#[test]
+fn miim_write_map_masks_self_clearing_bits() {
+    assert_eq!(<miim::Bcr as miim::Register>::SELF_CLEARING_MASK, 1 << 9);
+    let mut source = miim::Map::default();
+    source.bcr_mut().write().restart_an().set_bit();
+    source.link_md_mut().write().vct_enable().set_bit();
+
+    let mut miim = Miim(miim::Map::default());
+    miim.phy(0).write_map(&source).unwrap();
+    assert!(miim.0.bcr().read().restart_an().bit_is_clear());
+    assert!(miim.0.link_md().read().vct_enable().bit_is_clear());
+}
+
+#[test]
+fn miim_read_map() {
+    let mut device = miim::Map::default();
+    device.bsr_mut().write().bits(0xFFFF);
+    device.link_md_mut().write().vct_enable().set_bit();
+    let mut miim = Miim(device.clone());
+    assert_eq!(miim.phy(1).read_map().unwrap(), device);
+}
+
+#[test]
+fn miim_snapshot_restore() {
+    let mut device = miim::Map::default();
+    device.anar_mut().write().adv_pause().clear_bit();
+    let mut miim = Miim(device);
+    let snapshot = miim.phy(0).read_map().unwrap();
+
+    // Change both a writable and a read-only register on the device.
+    miim.0.anar_mut().write().adv_pause().set_bit();
+    miim.0.bcr_mut().write().power_down().set_bit();
+    miim.0.bsr_mut().write().bits(0xFFFF);
+
+    miim.phy(0).write_map(&snapshot).unwrap();
+    assert_eq!(miim.0.anar(), snapshot.anar());
+    assert_eq!(miim.0.bcr(), snapshot.bcr());
+    // The read-only register is not restored.
+    assert_eq!(miim.0.bsr().read().bits(), 0xFFFF);
+}
+
+// A mock MIIM interface that completes a cable test with an open fault as soon as it is enabled.
+struct OpenCable(miim::Map);
+
+impl mdio::miim::Read for OpenCable {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        self.0.read(phy_addr, reg_addr)
+    }
+}
+
+impl mdio::miim::Write for OpenCable {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
+        if reg_addr == u8::from(miim::Address::LinkMd) && data & (1 << 15) != 0 {
+            // Result `01` (open) with a fault count of 76, i.e. 20m.
+            return self.0.write(phy_addr, reg_addr, (0b01 << 13) | 76);
+        }
+        self.0.write(phy_addr, reg_addr, data)
+    }
+}
+
+#[test]
+fn miim_cable_diagnostics() {
+    let mut miim = Miim(OpenCable(miim::Map::default()));
+    let diagnostics = miim.phy(1).run_cable_diagnostics(4).unwrap();
+    assert_eq!(diagnostics.result, miim::VctStatus::Open);
+    assert_eq!(diagnostics.fault_count, 76);
+    assert_eq!(diagnostics.distance_to_fault, Some(2000));
+
+    let normal = miim::CableDiagnostics::from_vct(miim::VctStatus::Normal, 30);
+    assert_eq!(normal.distance_to_fault, None);
+}
+
+#[test]
+fn miim_link_status() {
+    let mut map = miim::Map::default();
+    // Link up and auto-negotiation complete.
+    map.bsr_mut().write().bits(0b0010_0100);
+    map.anlpar_mut()
+        .write()
+        .bits((1 << 10) | (1 << 7) | (1 << 5));
+    let mut miim = Miim(map);
+    let status = miim.phy(1).link_status().unwrap();
+    let expected = miim::LinkStatus {
+        up: true,
+        speed: miim::Speed::Mbps100,
+        duplex: miim::Duplex::Half,
+        an_done: true,
+        flow_control: miim::FlowControl::default(),
+    };
+    assert_eq!(status, expected);
+
+    miim.0.anlpar_mut().write().bits((1 << 10) | (1 << 8));
+    let status = miim.phy(1).link_status().unwrap();
+    assert_eq!(status.duplex, miim::Duplex::Full);
+    assert_eq!(
+        status.flow_control,
+        miim::FlowControl { tx: true, rx: true }
+    );
+}
+
+// A mock MIIM interface that sets `Bsr::an_complete` after the given number of reads.
+struct DelayedAn {
+    map: miim::Map,
+    reads_until_complete: usize,
+}
+
+impl mdio::miim::Read for DelayedAn {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        if reg_addr == u8::from(miim::Address::Bsr) && self.reads_until_complete > 0 {
+            self.reads_until_complete -= 1;
+            if self.reads_until_complete == 0 {
+                self.map.bsr_mut().write().bits(1 << 5);
+                self.map.anlpar_mut().write().bits((1 << 10) | (1 << 8));
+            }
+        }
+        self.map.read(phy_addr, reg_addr)
+    }
+}
+
+impl mdio::miim::Write for DelayedAn {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, phy_addr: u8, reg_addr: u8, data: u16) -> Result<(), Self::Error> {
+        self.map.write(phy_addr, reg_addr, data)
+    }
+}
+
+#[test]
+fn miim_restart_an_and_wait() {
+    let mock = DelayedAn {
+        map: miim::Map::default(),
+        reads_until_complete: 3,
+    };
+    let mut miim = Miim(mock);
+    let partner = miim.phy(1).restart_an_and_wait(5).unwrap();
+    assert!(partner.pause);
+    assert!(partner.full_duplex_100);
+    assert!(!partner.half_duplex_10);
+    assert!(miim
+        .0
+        .map
+        .reg::<miim::Bcr>()
+        .read()
+        .restart_an()
+        .bit_is_set());
+
+    let mock = DelayedAn {
+        map: miim::Map::default(),
+        reads_until_complete: 10,
+    };
+    let mut miim = Miim(mock);
+    match miim.phy(1).restart_an_and_wait(5) {
+        Err(ksz8863::PollError::Timeout) => (),
+        _ => panic!("expected timeout"),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn miim_map_serde_round_trip() {
+    let mut map = miim::Map::default();
+    map.bcr_mut().write().force_fd().set_bit();
+    let json = serde_json::to_string(&map).unwrap();
+    let restored: miim::Map = serde_json::from_str(&json).unwrap();
+    assert_eq!(map, restored);
+}
+
+#[test]
+fn miim_map_bytes_round_trip() {
+    let mut map = miim::Map::default();
+    map.phyidr2_mut().write().phy_id_low().bits(0xBEEF);
+    let bytes = map.to_bytes();
+    assert_eq!(bytes[3], 0xBEEF);
+    assert_eq!(miim::Map::from_bytes(&bytes), map);
+}
+
+// A mock MIIM interface that serves all reads via a single batched `read_many` call.
+#[derive(Default)]
+struct Batching {
+    map: miim::Map,
+    reads: usize,
+    batches: usize,
+}
+
+impl mdio::miim::Read for Batching {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        self.reads += 1;
+        self.map.read(phy_addr, reg_addr)
+    }
+}
+
+impl miim::ReadMany for Batching {
+    fn read_many(
+        &mut self,
+        phy_addr: u8,
+        reg_addrs: &[u8],
+        out: &mut [u16],
+    ) -> Result<(), Self::Error> {
+        self.batches += 1;
+        for (&reg_addr, data) in reg_addrs.iter().zip(out.iter_mut()) {
+            *data = mdio::miim::Read::read(&mut self.map, phy_addr, reg_addr)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn miim_read_all() {
+    let mut device = miim::Map::default();
+    device.bsr_mut().write().bits(0xFFFF);
+    device.link_md_mut().write().vct_enable().set_bit();
+
+    // The default `read_many` falls back to `read`.
+    let mut miim = Miim(device.clone());
+    assert_eq!(miim.phy(1).read_all().unwrap(), device);
+
+    let mut miim = Miim(Batching {
+        map: device.clone(),
+        ..Default::default()
+    });
+    assert_eq!(miim.phy(1).read_all().unwrap(), device);
+    assert_eq!(miim.0.batches, 1);
+    assert_eq!(miim.0.reads, 0);
+}
+
+#[test]
+fn miim_address_index() {
+    for (i, &addr) in miim::Address::ALL.iter().enumerate() {
+        assert_eq!(addr.index(), i);
+    }
+    assert_eq!(miim::Address::ALL.len(), miim::Map::LEN);
+}
+
+#[test]
+fn miim_counting_transactions() {
+    let mut miim = Miim(miim::Counting::new(miim::Map::default()));
+    miim.phy(1).read_map().unwrap();
+    assert_eq!(miim.0.reads(), miim::Map::LEN);
+    miim.0.reset();
+    miim.phy(1).read_all().unwrap();
+    assert_eq!(miim.0.reads(), 1);
+    miim.phy(1)
+        .reg::<miim::Bcr>()
+        .modify(|w| w.loopback().set_bit())
+        .unwrap();
+    assert_eq!(miim.0.reads(), 2);
+    assert_eq!(miim.0.writes(), 1);
+}
+
+// A mock MIIM interface whose reads fail the given number of times before succeeding.
+struct FlakyReads {
+    map: miim::Map,
+    failures: usize,
+}
+
+impl mdio::miim::Read for FlakyReads {
+    type Error = ();
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        if self.failures > 0 {
+            self.failures -= 1;
+            return Err(());
+        }
+        mdio::miim::Read::read(&mut self.map, phy_addr, reg_addr).map_err(|_| ())
+    }
+}
+
+#[test]
+fn miim_retry_flaky_reads() {
+    let flaky = FlakyReads {
+        map: miim::Map::default(),
+        failures: 3,
+    };
+    let mut miim = Miim(miim::Retry::new(flaky, 3));
+    assert!(miim.phy(1).reg::<miim::Bcr>().read().is_ok());
+    miim.0.inner_mut().failures = 4;
+    assert!(miim.phy(1).reg::<miim::Bcr>().read().is_err());
+}
+
+// A mock MIIM interface with a separate register map for each PHY address.
+#[derive(Default)]
+struct TwoPhys([miim::Map; 2]);
+
+impl mdio::miim::Read for TwoPhys {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, phy_addr: u8, reg_addr: u8) -> Result<u16, Self::Error> {
+        let map = &mut self.0[usize::from(phy_addr) - 1];
+        mdio::miim::Read::read(map, phy_addr, reg_addr)
+    }
+}
+
+#[test]
+fn miim_for_each_phy() {
+    let mut phys = TwoPhys::default();
+    phys.0[1].bcr_mut().write().loopback().set_bit();
+    let mut miim = Miim(phys);
+    let mut loopback = vec![];
+    miim.for_each_phy(|phy| {
+        let bcr = phy.reg::<miim::Bcr>().read()?;
+        loopback.push((phy.addr, bcr.read().loopback().bit()));
+        Ok::<_, ksz8863::InvalidAddress>(())
+    })
+    .unwrap();
+    assert_eq!(loopback, vec![(1, false), (2, true)]);
+}
+
+#[test]
+fn miim_far_end_fault() {
+    let mut device = miim::Map::default();
+    let mut miim = Miim(device.clone());
+    assert!(!miim.phy(1).far_end_fault().unwrap());
+    device.bsr_mut().write().bits(1 << 4);
+    let mut miim_fault = Miim(device);
+    assert!(miim_fault.phy(1).far_end_fault().unwrap());
+
+    miim.phy(1).set_far_end_fault_detection(false).unwrap();
+    let bcr = miim.0.bcr().read();
+    assert!(bcr.disable_far_end_fault().bit_is_set());
+    miim.phy(1).set_far_end_fault_detection(true).unwrap();
+    let bcr = miim.0.bcr().read();
+    assert!(bcr.disable_far_end_fault().bit_is_clear());
+}
+
+#[test]
+fn miim_set_loopback() {
+    use miim::LoopbackMode;
+    let mut miim = Miim(miim::Map::default());
+    miim.phy(1).set_loopback(LoopbackMode::Remote).unwrap();
+    assert!(miim.0.bcr().read().loopback().bit_is_clear());
+    assert!(miim.0.phy_special().read().remote_loopback().bit_is_set());
+    miim.phy(1).set_loopback(LoopbackMode::FarEnd).unwrap();
+    assert!(miim.0.bcr().read().loopback().bit_is_set());
+    assert!(miim.0.phy_special().read().remote_loopback().bit_is_clear());
+    miim.phy(1).set_loopback(LoopbackMode::Disabled).unwrap();
+    assert!(miim.0.bcr().read().loopback().bit_is_clear());
+    assert!(miim.0.phy_special().read().remote_loopback().bit_is_clear());
+}
+
+#[test]
+fn phy_read_raw() {
+    let mut miim = Miim(miim::Map::default());
+    let state = miim.phy(0).read_raw(0x01).unwrap();
+    assert_eq!(state.addr(), miim::Address::Bsr);
+    match miim.phy(0).read_raw(0x1E) {
+        Err(ksz8863::Error::InvalidAddress) => (),
+        _ => panic!("expected invalid address"),
+    }
+}
+
+#[test]
+fn address_display() {
+    assert_eq!(miim::Address::Bcr.to_string(), "Bcr");
+    assert_eq!(miim::Address::PhyIdR1.name(), "PhyIdR1");
+}
+
+#[test]
+fn address_from_str() {
+    assert_eq!("Bsr".parse::<miim::Address>().unwrap(), miim::Address::Bsr);
+    assert!("".parse::<miim::Address>().is_err());
+    for &addr in miim::Address::ALL {
+        assert_eq!(addr.to_string().parse::<miim::Address>().unwrap(), addr);
+    }
+}
+
+#[test]
+fn write_bits_masked() {
+    let mut anar = miim::Anar::from(0x0000);
+    anar.write().bits_masked(0xFFFF, 0x0F00);
+    let bits: u16 = anar.into();
+    assert_eq!(bits, 0x0F00);
+}
+
+#[cfg(feature = "trace")]
+#[test]
+fn miim_traced() {
+    use ksz8863::TransactionKind;
+    use miim::{Traced, Transaction};
+    let mut log = heapless::Vec::<Transaction, 8>::new();
+    {
+        let traced = Traced::new(miim::Map::default(), |t| log.push(t).unwrap());
+        let mut miim = Miim(traced);
+        miim.phy(2).bsr().read().unwrap();
+    }
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].kind, TransactionKind::Read);
+    assert_eq!(log[0].phy_addr, 2);
+    assert_eq!(log[0].reg_addr, 0x01);
+}
+
+#[test]
+fn miim_clone() {
+    let a = Miim(miim::Map::default());
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert_eq!(format!("{:?}", a), format!("{:?}", b));
+}
+
+#[test]
+fn miim_sim() {
+    let mut miim = Miim(miim::Sim::default());
+    miim.phy(1).restart_an_and_wait(1).unwrap();
+    let map = miim.0.map();
+    assert!(map.bcr().read().restart_an().bit_is_clear());
+    assert!(map.bsr().read().an_complete().bit_is_set());
+}
+
+#[test]
+fn phy_identify() {
+    let mut miim = Miim(miim::Map::default());
+    let id = miim.phy(1).identify().unwrap();
+    assert_eq!(id, miim::PhyId::KSZ8863);
+    assert!(id.is_ksz8863());
+    let other = miim::PhyId::from_regs(0x0022, 0x1431);
+    assert_eq!(other.revision, 1);
+    assert!(other.is_ksz8863());
+    assert!(!miim::PhyId::from_regs(0x0007, 0xC0F1).is_ksz8863());
+}
+
+#[test]
+fn phy_advertised_abilities() {
+    use miim::LinkAbilities;
+    let mut miim = Miim(miim::Map::default());
+    let all = LinkAbilities {
+        pause: true,
+        full_duplex_100: true,
+        half_duplex_100: true,
+        full_duplex_10: true,
+        half_duplex_10: true,
+    };
+    assert_eq!(miim.phy(1).advertised().unwrap(), all);
+    let abilities = LinkAbilities {
+        pause: false,
+        full_duplex_100: true,
+        half_duplex_100: false,
+        full_duplex_10: true,
+        half_duplex_10: false,
+    };
+    miim.phy(1).set_advertised(abilities).unwrap();
+    assert_eq!(miim.phy(1).advertised().unwrap(), abilities);
+    let bits: u16 = (*miim.0.anar()).into();
+    assert_eq!(bits, 0b0000_0001_0100_0000);
+
+    assert_eq!(
+        miim.phy(1).link_partner().unwrap(),
+        LinkAbilities::default()
+    );
+    miim.0.anlpar_mut().write().bits(0b0000_0101_0000_0000);
+    let partner = miim.phy(1).link_partner().unwrap();
+    assert!(partner.pause && partner.full_duplex_100 && !partner.half_duplex_10);
+}
+
+#[test]
+fn phy_force_link() {
+    use miim::{Duplex, Speed};
+    let mut miim = Miim(miim::Map::default());
+    miim.phy(1)
+        .force_link(Speed::Mbps100, Duplex::Half)
+        .unwrap();
+    let bcr = miim.0.bcr().read();
+    assert!(bcr.an_enable().bit_is_clear());
+    assert!(bcr.force_100().bit_is_set());
+    assert!(bcr.force_fd().bit_is_clear());
+    miim.phy(1).force_link(Speed::Mbps10, Duplex::Full).unwrap();
+    let bcr = miim.0.bcr().read();
+    assert!(bcr.force_100().bit_is_clear());
+    assert!(bcr.force_fd().bit_is_set());
+}
+
+#[test]
+fn phy_set_power_down() {
+    let mut miim = Miim(miim::Map::default());
+    miim.phy(2).set_power_down(true).unwrap();
+    assert!(miim.0.bcr().read().power_down().bit_is_set());
+    assert!(miim.0.bcr().read().an_enable().bit_is_set());
+    miim.phy(2).set_power_down(false).unwrap();
+    assert!(miim.0.bcr().read().power_down().bit_is_clear());
+}
+
+#[test]
+fn miim_phy_addrs() {
+    assert_eq!(miim::phy_addrs(0x01), miim::DEFAULT_PHY_ADDRS);
+    assert_eq!(miim::phy_addrs(0x04), [0x04, 0x05]);
+    assert_eq!(miim::phy_addrs(0x1F), [0x1F, 0x00]);
+    assert_eq!(miim::phy_addrs(0x21), [0x01, 0x02]);
+}
+
+#[test]
+fn miim_try_phy() {
+    let mut miim = Miim(miim::Map::default());
+    assert_eq!(miim.try_phy(0).unwrap().addr, 0);
+    assert_eq!(miim.try_phy(miim::MAX_PHY_ADDR).unwrap().addr, 31);
+    assert!(miim.try_phy(32).is_err());
+    assert!(miim.try_phy(0xFF).is_err());
+    assert_eq!(miim.phy(32).addr, 0);
+    assert_eq!(miim.phy(0x21).addr, 0x01);
+}
+
+#[test]
+fn miim_map_try_reg() {
+    let map = miim::Map::default();
+    assert_eq!(map.try_reg::<miim::Bcr>(), Some(&miim::Bcr::default()));
+    let state = map.state(miim::Address::Bsr);
+    assert!(state.as_reg::<miim::Bsr>().is_some());
+    assert!(state.as_reg::<miim::Bcr>().is_none());
+}
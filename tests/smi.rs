@@ -41,3 +41,2409 @@ fn smi_api() {
     // Check non-lexical borrows are working nicely.
     assert_eq!(a, smi.gc1().read().unwrap());
 }
+
+#[test]
+fn static_mac_table_round_trip() {
+    let mut smi = Smi(smi::Map::default());
+    let entry = smi::StaticMacEntry {
+        fid: 0x5,
+        use_fid: true,
+        override_stp: false,
+        valid: true,
+        forwarding_ports: 0b101,
+        mac_addr: [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB],
+    };
+    smi.static_mac_table().write_entry(3, &entry).unwrap();
+
+    // Check the entry was laid out across the indirect data registers as per the datasheet.
+    assert_eq!(
+        smi.indirect_data7().read().unwrap().read().bits(),
+        0b0000_0001
+    );
+    assert_eq!(
+        smi.indirect_data6().read().unwrap().read().bits(),
+        0b0110_1101
+    );
+    assert_eq!(smi.indirect_data5().read().unwrap().read().bits(), 0x01);
+    assert_eq!(smi.indirect_data0().read().unwrap().read().bits(), 0xAB);
+    let ctrl0 = smi.indirect_access_ctrl0().read().unwrap();
+    assert!(ctrl0.read().read_high_write_low().bit_is_clear());
+    assert_eq!(ctrl0.read().table_select().bits(), 0b00);
+    assert_eq!(smi.indirect_access_ctrl1().read().unwrap().read().bits(), 3);
+
+    assert_eq!(smi.static_mac_table().read_entry(3).unwrap(), entry);
+    let ctrl0 = smi.indirect_access_ctrl0().read().unwrap();
+    assert!(ctrl0.read().read_high_write_low().bit_is_set());
+}
+
+#[test]
+fn dynamic_mac_table_entry() {
+    let mut smi = Smi(smi::Map::default());
+
+    // An empty table.
+    smi.indirect_data8()
+        .write(|w| w.data().bits(0b100))
+        .unwrap();
+    assert_eq!(smi.dynamic_mac_table().len().unwrap(), 0);
+    assert_eq!(smi.dynamic_mac_table().entry(0).unwrap(), None);
+
+    // Preload the indirect data registers with a table of 3 entries, as the switch would.
+    let bytes = [0x00, 0x02, 0b0110_0011, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB];
+    for (addr, &byte) in (0x7B..=0x83).zip(bytes.iter()) {
+        let addr = core::convert::TryFrom::try_from(addr).unwrap();
+        smi.write(smi::State::from_addr_and_data(addr, byte))
+            .unwrap();
+    }
+    let mut table = smi.dynamic_mac_table();
+    assert_eq!(table.len().unwrap(), 3);
+    let entry = table.entry(1).unwrap().unwrap();
+    let expected = smi::DynamicMacEntry {
+        timestamp: 0b01,
+        source_port: 0b10,
+        fid: 0x3,
+        mac_addr: [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB],
+    };
+    assert_eq!(entry, expected);
+    assert_eq!(smi.indirect_access_ctrl1().read().unwrap().read().bits(), 1);
+}
+
+#[test]
+fn vlan_table_round_trip() {
+    let mut smi = Smi(smi::Map::default());
+    let entry = smi::VlanEntry {
+        valid: true,
+        membership: 0b011,
+        fid: 0x2,
+        vid: 0x123,
+    };
+    smi.vlan_table().write(15, &entry).unwrap();
+    assert_eq!(smi.indirect_data2().read().unwrap().read().bits(), 0x0B);
+    assert_eq!(smi.indirect_data1().read().unwrap().read().bits(), 0x21);
+    assert_eq!(smi.indirect_data0().read().unwrap().read().bits(), 0x23);
+    assert_eq!(smi.vlan_table().read(15).unwrap(), entry);
+    let ctrl0 = smi.indirect_access_ctrl0().read().unwrap();
+    assert_eq!(ctrl0.read().table_select().bits(), 0b01);
+
+    match smi.vlan_table().read(16) {
+        Err(smi::IndirectError::EntryOutOfRange) => (),
+        _ => panic!("expected `EntryOutOfRange`"),
+    }
+}
+
+#[test]
+fn mib_counter_indirect_addrs() {
+    use smi::{MibCounter, PortId};
+    assert_eq!(
+        MibCounter::RxLoPriorityByte.indirect_addr(PortId::Port1),
+        0x00
+    );
+    assert_eq!(
+        MibCounter::RxHiPriorityByte.indirect_addr(PortId::Port1),
+        0x01
+    );
+    assert_eq!(
+        MibCounter::RxLoPriorityByte.indirect_addr(PortId::Port2),
+        0x20
+    );
+    assert_eq!(MibCounter::TxUnicastPkts.indirect_addr(PortId::Port2), 0x3A);
+    assert_eq!(
+        MibCounter::RxLoPriorityByte.indirect_addr(PortId::Port3),
+        0x40
+    );
+    assert_eq!(
+        MibCounter::TxMultipleCollision.indirect_addr(PortId::Port3),
+        0x5F
+    );
+    assert_eq!(
+        MibCounter::TxDropPackets.indirect_addr(PortId::Port1),
+        0x100
+    );
+    assert_eq!(
+        MibCounter::TxDropPackets.indirect_addr(PortId::Port3),
+        0x102
+    );
+    assert_eq!(
+        MibCounter::RxDropPackets.indirect_addr(PortId::Port1),
+        0x103
+    );
+    assert_eq!(
+        MibCounter::RxDropPackets.indirect_addr(PortId::Port3),
+        0x105
+    );
+}
+
+#[test]
+fn mib_counter_read() {
+    let mut smi = Smi(smi::Map::default());
+    // Overflowed, valid count of 0x0123_4567.
+    smi.indirect_data3().write(|w| w.data().bits(0xC1)).unwrap();
+    smi.indirect_data2().write(|w| w.data().bits(0x23)).unwrap();
+    smi.indirect_data1().write(|w| w.data().bits(0x45)).unwrap();
+    smi.indirect_data0().write(|w| w.data().bits(0x67)).unwrap();
+    let reading = smi
+        .mib_counters()
+        .read_counter(smi::PortId::Port2, smi::MibCounter::RxCrcError)
+        .unwrap();
+    let expected = smi::MibReading {
+        value: 0x0123_4567,
+        overflow: true,
+        valid: true,
+    };
+    assert_eq!(reading, expected);
+    let ctrl0 = smi.indirect_access_ctrl0().read().unwrap();
+    assert_eq!(ctrl0.read().table_select().bits(), 0b11);
+    assert_eq!(ctrl0.read().indirect_addr_high().bits(), 0);
+    assert_eq!(
+        smi.indirect_access_ctrl1().read().unwrap().read().bits(),
+        0x27
+    );
+}
+
+#[test]
+fn indirect_byte_order() {
+    let mut smi = Smi(smi::Map::default());
+    let data = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x42];
+    for (addr, &byte) in (0x7B..=0x83).zip(data.iter()) {
+        let addr = core::convert::TryFrom::try_from(addr).unwrap();
+        smi.write(smi::State::from_addr_and_data(addr, byte))
+            .unwrap();
+    }
+    let read = smi.indirect().read(smi::Table::Vlan, 0x2FF).unwrap();
+    assert_eq!(read, data);
+    let ctrl0 = smi.indirect_access_ctrl0().read().unwrap();
+    assert!(ctrl0.read().read_high_write_low().bit_is_set());
+    assert_eq!(ctrl0.read().table_select().bits(), 0b01);
+    assert_eq!(ctrl0.read().indirect_addr_high().bits(), 0b10);
+    assert_eq!(
+        smi.indirect_access_ctrl1().read().unwrap().read().bits(),
+        0xFF
+    );
+
+    let data = [0x00, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+    smi.indirect()
+        .write(smi::Table::StaticMac, 1, &data)
+        .unwrap();
+    assert_eq!(smi.indirect_data7().read().unwrap().read().bits(), 0xFE);
+    assert_eq!(smi.indirect_data0().read().unwrap().read().bits(), 0x10);
+    assert_eq!(smi.indirect().read_data().unwrap(), data);
+}
+
+#[test]
+fn limit_mode_variant() {
+    let mut smi = Smi(smi::Map::default());
+    let ctrl5 = smi.port2_ctrl5().read().unwrap();
+    assert_eq!(
+        ctrl5.read().limit_mode().variant().unwrap(),
+        smi::LimitMode::All
+    );
+    smi.port2_ctrl5()
+        .modify(|w| w.limit_mode().variant(smi::LimitMode::Broadcast))
+        .unwrap();
+    let ctrl5 = smi.port2_ctrl5().read().unwrap();
+    assert_eq!(
+        ctrl5.read().limit_mode().variant().unwrap(),
+        smi::LimitMode::Broadcast
+    );
+    assert_eq!(ctrl5.read().limit_mode().bits(), 0b11);
+    smi.port2_ctrl5()
+        .modify(|w| w.limit_mode().bits(0b01))
+        .unwrap();
+    let ctrl5 = smi.port2_ctrl5().read().unwrap();
+    let expected = smi::LimitMode::BroadcastMulticastFlooded;
+    assert_eq!(ctrl5.read().limit_mode().variant().unwrap(), expected);
+}
+
+#[test]
+fn host_iface_mode_variants() {
+    let mut smi = Smi(smi::Map::default());
+    let modes = [
+        smi::HostIfaceMode::I2cMaster,
+        smi::HostIfaceMode::I2cSlave,
+        smi::HostIfaceMode::SpiSlave,
+        smi::HostIfaceMode::Smi,
+    ];
+    for &mode in modes.iter() {
+        smi.fwd_invalid_vid_frame_and_host_mode()
+            .modify(|w| w.host_iface_mode().variant(mode))
+            .unwrap();
+        let reg = smi.fwd_invalid_vid_frame_and_host_mode().read().unwrap();
+        assert_eq!(reg.read().host_iface_mode().variant().unwrap(), mode);
+        assert_eq!(reg.read().host_iface_mode().bits(), u8::from(mode));
+    }
+}
+
+#[test]
+fn pwr_mgmt_mode_variants() {
+    let mut smi = Smi(smi::Map::default());
+    let reg = smi.pwr_mgmt_and_led_mode().read().unwrap();
+    let mode = reg.read().pwr_mgmt_mode().variant().unwrap();
+    assert_eq!(mode, smi::PwrMgmtMode::Normal);
+
+    smi.pwr_mgmt_and_led_mode()
+        .modify(|w| w.pwr_mgmt_mode().energy_detect())
+        .unwrap();
+    let reg = smi.pwr_mgmt_and_led_mode().read().unwrap();
+    let mode = reg.read().pwr_mgmt_mode().variant().unwrap();
+    assert_eq!(mode, smi::PwrMgmtMode::EnergyDetect);
+
+    smi.pwr_mgmt_and_led_mode()
+        .modify(|w| w.pwr_mgmt_mode().soft_power_down())
+        .unwrap();
+    let reg = smi.pwr_mgmt_and_led_mode().read().unwrap();
+    let mode = reg.read().pwr_mgmt_mode().variant().unwrap();
+    assert_eq!(mode, smi::PwrMgmtMode::SoftPowerDown);
+
+    smi.pwr_mgmt_and_led_mode()
+        .modify(|w| w.pwr_mgmt_mode().power_saving())
+        .unwrap();
+    let reg = smi.pwr_mgmt_and_led_mode().read().unwrap();
+    let mode = reg.read().pwr_mgmt_mode().variant().unwrap();
+    assert_eq!(mode, smi::PwrMgmtMode::PowerSaving);
+
+    smi.pwr_mgmt_and_led_mode()
+        .modify(|w| w.pwr_mgmt_mode().normal())
+        .unwrap();
+    let reg = smi.pwr_mgmt_and_led_mode().read().unwrap();
+    let mode = reg.read().pwr_mgmt_mode().variant().unwrap();
+    assert_eq!(mode, smi::PwrMgmtMode::Normal);
+}
+
+#[test]
+fn port_default_tag() {
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.port(smi::PortId::Port2).default_tag().unwrap(), 0x0001);
+    smi.port(smi::PortId::Port2)
+        .set_default_tag(0x1234)
+        .unwrap();
+    let ctrl3 = smi.port2_ctrl3().read().unwrap();
+    let ctrl4 = smi.port2_ctrl4().read().unwrap();
+    assert_eq!(ctrl3.read().default_tag_15_8().bits(), 0x12);
+    assert_eq!(ctrl4.read().default_tag_7_0().bits(), 0x34);
+    assert_eq!(smi.port(smi::PortId::Port2).default_tag().unwrap(), 0x1234);
+    assert_eq!(smi.port(smi::PortId::Port1).default_tag().unwrap(), 0x0001);
+
+    let tag = smi::VlanTag::from(0xB234);
+    assert_eq!(tag.priority, 0b101);
+    assert!(tag.cfi);
+    assert_eq!(tag.vid, 0x234);
+    assert_eq!(u16::from(tag), 0xB234);
+}
+
+#[test]
+fn port_registers() {
+    let mut smi = Smi(smi::Map::default());
+    for &id in smi::PortId::ALL {
+        let membership = 1 << id.index();
+        smi.port(id)
+            .ctrl1()
+            .modify(|w| w.port_vlan_membership().bits(membership))
+            .unwrap();
+        let ctrl1 = smi.port(id).ctrl1().read().unwrap();
+        assert_eq!(ctrl1.read().port_vlan_membership().bits(), membership);
+        let is_phy_port = id != smi::PortId::Port3;
+        assert_eq!(smi.port(id).status0().is_ok(), is_phy_port);
+        assert_eq!(smi.port(id).ctrl12().is_ok(), is_phy_port);
+    }
+    let port1 = smi.port1_ctrl1().read().unwrap();
+    let port2 = smi.port2_ctrl1().read().unwrap();
+    let port3 = smi.port3_ctrl1().read().unwrap();
+    assert_eq!(port1.read().port_vlan_membership().bits(), 0b001);
+    assert_eq!(port2.read().port_vlan_membership().bits(), 0b010);
+    assert_eq!(port3.read().port_vlan_membership().bits(), 0b100);
+
+    // Bits only present on port 3 are preserved when modified via the port 1 layout.
+    smi.port3_q0_ingress_rate_limit()
+        .modify(|w| w.rmii_refclk_invert().set_bit())
+        .unwrap();
+    smi.port(smi::PortId::Port3)
+        .q0_ingress_rate_limit()
+        .modify(|w| w.limit().bits(0x10))
+        .unwrap();
+    let q0 = smi.port3_q0_ingress_rate_limit().read().unwrap();
+    assert!(q0.read().rmii_refclk_invert().bit_is_set());
+    assert_eq!(q0.read().limit().bits(), 0x10);
+}
+
+#[test]
+fn broadcast_storm_rate() {
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.broadcast_storm_rate().unwrap(), 0x063);
+
+    smi.set_broadcast_storm_rate(0x5A3).unwrap();
+    let gc4 = smi.gc4().read().unwrap();
+    let gc5 = smi.gc5().read().unwrap();
+    assert_eq!(
+        gc4.read().broadcast_storm_protection_rate_high().bits(),
+        0b101
+    );
+    assert_eq!(
+        gc5.read().broadcast_storm_protection_rate_low().bits(),
+        0xA3
+    );
+    assert!(gc4.read().mii_10_bt().bit_is_set());
+    assert_eq!(smi.broadcast_storm_rate().unwrap(), 0x5A3);
+
+    smi.set_broadcast_storm_rate(0xFFFF).unwrap();
+    let max = smi::MAX_BROADCAST_STORM_RATE;
+    assert_eq!(smi.broadcast_storm_rate().unwrap(), max);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn smi_async_api() {
+    use ksz8863::smi::{AsyncRead, AsyncWrite, Read, Write};
+
+    // A mock asynchronous interface backed by a `Map`.
+    struct AsyncMap(smi::Map);
+
+    impl AsyncRead for AsyncMap {
+        type Error = ksz8863::InvalidAddress;
+        async fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+            Read::read(&mut self.0, reg_addr)
+        }
+    }
+
+    impl AsyncWrite for AsyncMap {
+        type Error = ksz8863::InvalidAddress;
+        async fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+            Write::write(&mut self.0, reg_addr, data)
+        }
+    }
+
+    let mut smi = Smi(AsyncMap(smi::Map::default()));
+    futures::executor::block_on(async {
+        let gc1 = smi.gc1().read_async().await.unwrap();
+        assert_eq!(gc1, smi::Gc1::default());
+        smi.gc1()
+            .modify_async(|w| w.tx_flow_control().clear_bit())
+            .await
+            .unwrap();
+        let gc1 = smi.gc1().read_async().await.unwrap();
+        assert!(gc1.read().tx_flow_control().bit_is_clear());
+        smi.gc1().write_async(|w| w.reset()).await.unwrap();
+        let state = smi.read_async(smi::Address::Gc1).await.unwrap();
+        assert_eq!(state, smi::State::Gc1(smi::Gc1::default()));
+    });
+}
+
+#[test]
+fn smi_map_diff() {
+    let a = smi::Map::default();
+    let mut b = a.clone();
+    assert_eq!(a.diff(&b).count(), 0);
+    b.gc1_mut().write().aging().clear_bit();
+    b.port2_ctrl1_mut()
+        .write()
+        .port_vlan_membership()
+        .bits(0b010);
+    let addrs: Vec<_> = a.diff(&b).map(|(addr, _, _)| addr).collect();
+    assert_eq!(addrs, vec![smi::Address::Gc1, smi::Address::Port2Ctrl1]);
+    let (_, old, new) = a.diff(&b).next().unwrap();
+    assert_eq!(*old, smi::State::Gc1(smi::Gc1::default()));
+    assert_eq!(*new, b[smi::Address::Gc1]);
+}
+
+#[test]
+fn smi_map_iter() {
+    let mut map = smi::Map::default();
+    assert_eq!(map.iter().count(), smi::Map::LEN);
+    for ((addr, state), &expected) in map.iter().zip(smi::Address::ALL) {
+        assert_eq!(addr, expected);
+        assert_eq!(state.addr(), expected);
+    }
+    for (addr, mut state) in map.iter_mut() {
+        if addr == smi::Address::Gc1 {
+            state
+                .reg_mut::<smi::Gc1>()
+                .unwrap()
+                .write()
+                .aging()
+                .clear_bit();
+            assert!(state.set(smi::State::Gc2(Default::default())).is_err());
+        }
+    }
+    assert_eq!(map.iter_mut().count(), smi::Map::LEN);
+    let gc1 = map.reg::<smi::Gc1>();
+    assert!(gc1.read().aging().bit_is_clear());
+}
+
+#[test]
+fn smi_write_map() {
+    assert_eq!(<smi::Gc9 as smi::Register>::WRITABLE_MASK, 0b1100_0000);
+    assert_eq!(<smi::Port1Status0 as smi::Register>::WRITABLE_MASK, 0);
+
+    let mut source = smi::Map::default();
+    source.gc1_mut().write().aging().clear_bit();
+    source.port3_ctrl5_mut().write().limit_mode().bits(0b11);
+    source.port1_status0_mut().write().bits(0xFF);
+
+    let mut smi = Smi(smi::Map::default());
+    smi.write_map(&source).unwrap();
+    let device = smi.0;
+    assert_eq!(device.reg::<smi::Gc1>(), source.reg::<smi::Gc1>());
+    assert_eq!(
+        device.reg::<smi::Port3Ctrl5>(),
+        source.reg::<smi::Port3Ctrl5>()
+    );
+    // Read-only registers are not written.
+    assert_eq!(
+        device.reg::<smi::Port1Status0>(),
+        &smi::Port1Status0::default()
+    );
+}
+
+#[test]
+fn smi_write_map_skips_side_effects() {
+    let mut source = smi::Map::default();
+    source.indirect_access_ctrl1_mut().write().bits(0x01);
+    source.link_change_interrupt_mut().write().p1().set_bit();
+    source.reset_mut().write().software().set_bit();
+    let mut smi = Smi(RecordWrites::default());
+    smi.write_map(&source).unwrap();
+    for addr in NO_BULK_WRITE.iter() {
+        assert!(!addr.is_bulk_writable());
+        assert!(!smi.0.addrs.contains(&u8::from(*addr)));
+    }
+    assert!(smi.0.addrs.contains(&u8::from(smi::Address::IndirectData0)));
+}
+
+#[test]
+fn smi_write_map_masks_self_clearing_bits() {
+    assert_eq!(
+        <smi::Gc0 as smi::Register>::SELF_CLEARING_MASK,
+        smi::Address::Gc0.self_clearing_mask()
+    );
+    let mut source = smi::Map::default();
+    source.gc0_mut().write().flush_dynamic_mac_table().set_bit();
+    source.port1_ctrl13_mut().write().restart_an().set_bit();
+    source.port2_phy_special_mut().write().vct_en().set_bit();
+
+    let mut smi = Smi(smi::Map::default());
+    smi.write_map(&source).unwrap();
+    assert!(smi.0.gc0().read().flush_dynamic_mac_table().bit_is_clear());
+    assert!(smi.0.port1_ctrl13().read().restart_an().bit_is_clear());
+    assert!(smi.0.port2_phy_special().read().vct_en().bit_is_clear());
+
+    let mut smi = Smi(smi::Map::default());
+    smi.write_diff(&smi::Map::default(), &source).unwrap();
+    assert!(smi.0.gc0().read().flush_dynamic_mac_table().bit_is_clear());
+    assert!(smi.0.port1_ctrl13().read().restart_an().bit_is_clear());
+    assert!(smi.0.port2_phy_special().read().vct_en().bit_is_clear());
+}
+
+#[test]
+fn smi_read_map() {
+    let mut device = smi::Map::default();
+    device.gc1_mut().write().aging().clear_bit();
+    device.port1_status0_mut().write().bits(0b0010_0000);
+    let mut smi = Smi(device.clone());
+    assert_eq!(smi.read_map().unwrap(), device);
+}
+
+#[test]
+fn smi_software_reset() {
+    let mut smi = Smi(smi::Map::default());
+    smi.software_reset().unwrap();
+    let reset = smi.reset().read().unwrap();
+    assert!(reset.read().software().bit_is_set());
+}
+
+#[test]
+fn switch_mac_addr() {
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(
+        smi.mac_addr().unwrap(),
+        [0x00, 0x10, 0xA1, 0xFF, 0xFF, 0xFF]
+    );
+    let mac_addr = [0x02, 0x12, 0x34, 0x56, 0x78, 0x9A];
+    smi.set_mac_addr(mac_addr).unwrap();
+    assert_eq!(smi.mac_addr().unwrap(), mac_addr);
+    let msb = smi.mac_addr_0().read().unwrap();
+    let lsb = smi.mac_addr_5().read().unwrap();
+    assert_eq!(msb.read().data().bits(), 0x02);
+    assert_eq!(lsb.read().data().bits(), 0x9A);
+}
+
+#[test]
+fn station_mac_addrs() {
+    let mut smi = Smi(smi::Map::default());
+    let mac_addr1 = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+    let mac_addr2 = [0x02, 0x00, 0x00, 0x00, 0x00, 0x02];
+    smi.set_station_mac_addr(smi::Station::Station1, mac_addr1)
+        .unwrap();
+    smi.set_station_mac_addr(smi::Station::Station2, mac_addr2)
+        .unwrap();
+    let station1 = smi.station_mac_addr(smi::Station::Station1).unwrap();
+    let station2 = smi.station_mac_addr(smi::Station::Station2).unwrap();
+    assert_eq!(station1, mac_addr1);
+    assert_eq!(station2, mac_addr2);
+    let lsb = smi.station2_mac_addr5().read().unwrap();
+    assert_eq!(lsb.read().data().bits(), 0x02);
+}
+
+// A mock SMI interface that completes a cable test with the given result and fault count as soon
+// as it is enabled.
+struct CableTest {
+    map: smi::Map,
+    result: u8,
+    fault_count: u16,
+}
+
+impl smi::Read for CableTest {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.map.read(reg_addr)
+    }
+}
+
+impl smi::Write for CableTest {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        let phy_special = [smi::Address::Port1PhySpecial, smi::Address::Port2PhySpecial];
+        if phy_special.iter().any(|&a| u8::from(a) == reg_addr) && data & (1 << 4) != 0 {
+            let high = (self.fault_count >> 8) as u8 & 1;
+            self.map.write(reg_addr, (self.result << 5) | high)?;
+            return self.map.write(reg_addr + 1, self.fault_count as u8);
+        }
+        self.map.write(reg_addr, data)
+    }
+}
+
+#[test]
+fn smi_cable_diagnostics() {
+    use smi::{PortId, VctStatus};
+
+    let cases = [
+        (PortId::Port1, VctStatus::Open, 300, Some(10_960)),
+        (PortId::Port2, VctStatus::Short, 46, Some(800)),
+        (PortId::Port2, VctStatus::Normal, 0, None),
+    ];
+    for &(port, result, fault_count, distance) in cases.iter() {
+        let mock = CableTest {
+            map: smi::Map::default(),
+            result: result.into(),
+            fault_count,
+        };
+        let mut smi = Smi(mock);
+        let diagnostics = smi.port(port).run_cable_diagnostics(4).unwrap();
+        assert_eq!(diagnostics.result, result);
+        assert_eq!(diagnostics.fault_count, fault_count);
+        assert_eq!(diagnostics.distance_to_fault, distance);
+    }
+
+    let mut smi = Smi(smi::Map::default());
+    match smi.port(PortId::Port3).run_cable_diagnostics(4) {
+        Err(smi::PortError::UnsupportedRegister) => (),
+        _ => panic!("expected unsupported register"),
+    }
+}
+
+#[test]
+fn port_link_status() {
+    let mut map = smi::Map::default();
+    // Link good and auto-negotiation done.
+    map.port2_status0_mut().write().bits(0b0110_0000);
+    // Transmit flow control, 100BT, full duplex.
+    map.port2_status1_mut().write().bits(0b0001_0110);
+    let mut smi = Smi(map);
+    let status = smi.port(smi::PortId::Port2).link_status().unwrap();
+    let expected = smi::LinkStatus {
+        up: true,
+        speed: smi::Speed::Mbps100,
+        duplex: smi::Duplex::Full,
+        an_done: true,
+        flow_control: smi::FlowControl {
+            tx: true,
+            rx: false,
+        },
+    };
+    assert_eq!(status, expected);
+
+    let status = smi.port(smi::PortId::Port1).link_status().unwrap();
+    assert!(!status.up);
+    assert_eq!(status.speed, smi::Speed::Mbps10);
+    assert!(smi.port(smi::PortId::Port3).link_status().is_err());
+}
+
+#[test]
+fn port_restart_an_and_wait() {
+    let mut map = smi::Map::default();
+    // Auto-negotiation done with a 100BT full duplex partner.
+    map.port1_status0_mut().write().bits(0b0100_1000);
+    let mut smi = Smi(map);
+    let partner = smi.port(smi::PortId::Port1).restart_an_and_wait(4).unwrap();
+    assert!(partner.full_duplex_100);
+    assert!(!partner.pause);
+    let ctrl13 = smi.port1_ctrl13().read().unwrap();
+    assert!(ctrl13.read().restart_an().bit_is_set());
+
+    match smi.port(smi::PortId::Port2).restart_an_and_wait(4) {
+        Err(smi::PortError::Timeout) => (),
+        _ => panic!("expected timeout"),
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt_format_impls() {
+    fn assert_format<T: defmt::Format>() {}
+    assert_format::<smi::Gc1>();
+    assert_format::<smi::State>();
+    assert_format::<smi::Address>();
+    assert_format::<smi::LimitMode>();
+    assert_format::<ksz8863::miim::Bcr>();
+    assert_format::<ksz8863::miim::State>();
+    assert_format::<ksz8863::miim::Address>();
+}
+
+// Never called, but checks that registers may be passed to the `defmt` formatting macros.
+#[cfg(feature = "defmt")]
+#[allow(dead_code)]
+fn defmt_write_registers(f: defmt::Formatter, gc1: &smi::Gc1, bcr: &ksz8863::miim::Bcr) {
+    defmt::write!(f, "{} {}", gc1, bcr);
+}
+
+#[test]
+fn address_range() {
+    use smi::Address;
+    let port2: Vec<_> = Address::range(Address::Port2Ctrl0, Address::Port2Status1).collect();
+    let expected: Vec<_> = Address::ALL
+        .iter()
+        .cloned()
+        .filter(|&addr| (0x20..=0x2F).contains(&u8::from(addr)))
+        .collect();
+    assert_eq!(port2, expected);
+    assert_eq!(port2.first(), Some(&Address::Port2Ctrl0));
+    assert_eq!(port2.last(), Some(&Address::Port2Status1));
+    assert_eq!(port2.len(), 16);
+
+    // Gaps are skipped.
+    let port3: Vec<_> = Address::range(Address::Port3Ctrl0, Address::Port3Status1).collect();
+    assert_eq!(port3.len(), 11);
+    assert_eq!(Address::range(Address::Gc1, Address::ChipId0).count(), 0);
+}
+
+#[test]
+fn map_short_hand_accessors() {
+    let mut map = smi::Map::default();
+    map.gc1_mut().write().aging().clear_bit();
+    let map = map;
+    assert!(map.gc1().read().aging().bit_is_clear());
+    assert_eq!(map.gc2(), &smi::Gc2::default());
+}
+
+#[test]
+fn map_get_raw_addr() {
+    let mut map = smi::Map::default();
+    let gc1 = map.get(0x03).unwrap();
+    assert_eq!(gc1.addr(), smi::Address::Gc1);
+    assert!(map.get(0x08).is_none());
+    assert!(map.get(0xFF).is_none());
+    let mut state = map.get_mut(0x03).unwrap();
+    state
+        .reg_mut::<smi::Gc1>()
+        .unwrap()
+        .write()
+        .aging()
+        .clear_bit();
+    assert!(map.gc1().read().aging().bit_is_clear());
+    assert!(map.get_mut(0x08).is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn map_serde_round_trip() {
+    let mut map = smi::Map::default();
+    map.gc1_mut().write().aging().clear_bit();
+    map.port2_ctrl1_mut()
+        .write()
+        .port_vlan_membership()
+        .bits(0b010);
+    let json = serde_json::to_string(&map).unwrap();
+    let restored: smi::Map = serde_json::from_str(&json).unwrap();
+    assert_eq!(map, restored);
+
+    // A truncated sequence of states is rejected.
+    let states: Vec<smi::State> = map.iter().map(|(_, &state)| state).collect();
+    let json = serde_json::to_string(&states[..smi::Map::LEN - 1]).unwrap();
+    assert!(serde_json::from_str::<smi::Map>(&json).is_err());
+
+    // As are out of order states.
+    let mut states = states;
+    states.swap(0, 1);
+    let json = serde_json::to_string(&states).unwrap();
+    assert!(serde_json::from_str::<smi::Map>(&json).is_err());
+}
+
+#[test]
+fn map_bytes_round_trip() {
+    let mut map = smi::Map::default();
+    map.gc1_mut().write().aging().clear_bit();
+    map.mac_addr_5_mut().write().data().bits(0x42);
+    let bytes = map.to_bytes();
+    assert_eq!(bytes.len(), smi::Map::LEN);
+    let ix = smi::Address::ALL
+        .iter()
+        .position(|&addr| addr == smi::Address::MacAddr5)
+        .unwrap();
+    assert_eq!(bytes[ix], 0x42);
+    assert_eq!(smi::Map::from_bytes(&bytes), map);
+}
+
+#[test]
+fn validate_reserved_bits() {
+    let mut map = smi::Map::default();
+    for (_, state) in map.iter() {
+        state.validate().unwrap();
+    }
+    map.gc9_mut().write().bits(0b1000_0000);
+    let err = map[smi::Address::Gc9].validate().unwrap_err();
+    assert_eq!(err.addr, smi::Address::Gc9);
+    assert_eq!(err.field, "reserved");
+}
+
+#[test]
+fn writable_addresses() {
+    use smi::Address;
+    assert!(Address::Gc1.is_writable());
+    assert!(!Address::Port1Status0.is_writable());
+    let writable: Vec<_> = Address::writable().collect();
+    assert!(writable.contains(&Address::Gc1));
+    assert!(!writable.contains(&Address::Port1Status0));
+    assert!(!writable.contains(&Address::Port3Status1));
+    assert!(writable.len() < Address::ALL.len());
+}
+
+#[test]
+fn state_fields() {
+    use ksz8863::Access;
+    let mut gc1 = smi::Gc1::default();
+    gc1.write().aging().clear_bit();
+    let state = smi::State::Gc1(gc1);
+    let fields: Vec<_> = state.fields().collect();
+    let names: Vec<_> = fields.iter().map(|f| f.name).collect();
+    assert_eq!(
+        names,
+        vec![
+            "pass_all_frames",
+            "port3_tail_tag",
+            "tx_flow_control",
+            "rx_flow_control",
+            "frame_length_field_check",
+            "aging",
+            "fast_age",
+            "aggressive_back_off",
+        ]
+    );
+    let aging = fields.iter().find(|f| f.name == "aging").unwrap();
+    assert_eq!(aging.bit_range, (2, 2));
+    assert_eq!(aging.value, 0);
+    assert_eq!(aging.access, Access::RW);
+
+    let gc9 = smi::State::Gc9(Default::default());
+    let reserved = gc9.fields().find(|f| f.name == "reserved").unwrap();
+    assert_eq!(reserved.bit_range, (2, 3));
+    assert_eq!(reserved.value, 0b10);
+    assert_eq!(reserved.access, Access::R);
+}
+
+#[test]
+fn smi_modify_state() {
+    let mut smi = Smi(smi::Map::default());
+    smi.modify_state(smi::Address::Gc1, |state| {
+        if let smi::State::Gc1(gc1) = state {
+            gc1.write().aging().clear_bit();
+        }
+    })
+    .unwrap();
+    assert!(smi.0.gc1().read().aging().bit_is_clear());
+    assert!(smi.0.gc1().read().tx_flow_control().bit_is_set());
+
+    // Changing the variant is rejected without writing.
+    let mut smi = Smi(CountWrites::default());
+    let result = smi.modify_state(smi::Address::Gc1, |state| {
+        *state = smi::State::Gc2(smi::Gc2::default());
+    });
+    assert!(matches!(result, Err(ksz8863::Error::AddressChanged)));
+    assert_eq!(smi.0.writes, 0);
+}
+
+#[test]
+fn smi_flow_control() {
+    let mut smi = Smi(smi::Map::default());
+    let config = smi::FlowControlConfig {
+        global_tx: false,
+        global_rx: true,
+        port_force: [true, false, true],
+    };
+    smi.set_flow_control(config).unwrap();
+    let gc1 = smi.0.gc1().read();
+    assert!(gc1.tx_flow_control().bit_is_clear());
+    assert!(gc1.rx_flow_control().bit_is_set());
+    assert!(gc1.aging().bit_is_set());
+    assert!(smi.0.port1_ctrl2().read().force_flow_control().bit_is_set());
+    assert!(smi
+        .0
+        .port2_ctrl2()
+        .read()
+        .force_flow_control()
+        .bit_is_clear());
+    assert!(smi.0.port1_ctrl2().read().transmit().bit_is_set());
+    assert_eq!(smi.flow_control().unwrap(), config);
+}
+
+#[test]
+fn cpu_iface_clk_variants() {
+    let mut smi = Smi(smi::Map::default());
+    let gc9 = smi.gc9().read().unwrap();
+    assert_eq!(
+        gc9.read().cpu_iface_clk().variant().unwrap(),
+        smi::CpuIfaceClk::Mhz125
+    );
+    let clks = [
+        smi::CpuIfaceClk::Mhz31_25,
+        smi::CpuIfaceClk::Mhz62_5,
+        smi::CpuIfaceClk::Mhz125,
+    ];
+    for &clk in clks.iter() {
+        smi.gc9()
+            .modify(|w| w.cpu_iface_clk().variant(clk))
+            .unwrap();
+        let gc9 = smi.gc9().read().unwrap();
+        assert_eq!(gc9.read().cpu_iface_clk().variant().unwrap(), clk);
+        assert_eq!(gc9.read().cpu_iface_clk().bits(), u8::from(clk));
+        assert_eq!(gc9.read().reserved().bits(), 0b10);
+        assert!(smi::State::Gc9(gc9).validate().is_ok());
+    }
+    smi.gc9().modify(|w| w.cpu_iface_clk().bits(0b11)).unwrap();
+    let gc9 = smi.gc9().read().unwrap();
+    assert!(gc9.read().cpu_iface_clk().variant().is_err());
+}
+
+#[test]
+fn priority_queue_map() {
+    use smi::Queue::{Q0, Q1, Q2, Q3};
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(
+        smi.priority_queue_map().unwrap(),
+        [Q0, Q0, Q1, Q1, Q2, Q2, Q3, Q3]
+    );
+    let map = [Q3, Q2, Q1, Q0, Q0, Q1, Q2, Q3];
+    smi.set_priority_queue_map(map).unwrap();
+    assert_eq!(smi.priority_queue_map().unwrap(), map);
+    let (gc10, gc11): (u8, u8) = ((*smi.0.gc10()).into(), (*smi.0.gc11()).into());
+    assert_eq!(gc10, 0b00_01_10_11);
+    assert_eq!(gc11, 0b11_10_01_00);
+}
+
+#[test]
+fn port_vlan_membership_set() {
+    use smi::{PortId, PortSet};
+    let mut smi = Smi(smi::Map::default());
+    let ctrl1 = smi.port2_ctrl1().read().unwrap();
+    assert_eq!(ctrl1.read().port_vlan_membership().ports(), PortSet::ALL);
+
+    let mut ports = PortSet::EMPTY;
+    ports.insert(PortId::Port1);
+    ports.insert(PortId::Port3);
+    smi.port2_ctrl1()
+        .modify(|w| w.port_vlan_membership().ports(ports))
+        .unwrap();
+    let ctrl1 = smi.port2_ctrl1().read().unwrap();
+    assert_eq!(ctrl1.read().port_vlan_membership().bits(), 0b101);
+    let ports = ctrl1.read().port_vlan_membership().ports();
+    assert!(ports.contains(PortId::Port1));
+    assert!(!ports.contains(PortId::Port2));
+    assert!(ports.contains(PortId::Port3));
+    assert_eq!(
+        ports.iter().collect::<Vec<_>>(),
+        vec![PortId::Port1, PortId::Port3]
+    );
+
+    let mut ports = PortSet::ALL;
+    ports.remove(PortId::Port1);
+    assert_eq!(ports.bits(), 0b110);
+    assert_eq!(PortSet::single(PortId::Port2).bits(), 0b010);
+    assert_eq!(PortSet::from(0xFF), PortSet::ALL);
+    assert!(PortSet::EMPTY.is_empty());
+}
+
+// A mock SMI interface where the `LinkChangeInterrupt` bits are cleared by writing `1`.
+struct WriteOneToClear(smi::Map);
+
+impl smi::Read for WriteOneToClear {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.0.read(reg_addr)
+    }
+}
+
+impl smi::Write for WriteOneToClear {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        if reg_addr == u8::from(smi::Address::LinkChangeInterrupt) {
+            let pending: u8 = (*self.0.link_change_interrupt()).into();
+            return self.0.write(reg_addr, pending & !data);
+        }
+        self.0.write(reg_addr, data)
+    }
+}
+
+#[test]
+fn smi_interrupts() {
+    let mut smi = Smi(WriteOneToClear(smi::Map::default()));
+    assert_eq!(smi.interrupt_status().unwrap(), smi::Interrupts::NONE);
+
+    let enable = smi::Interrupts {
+        p1_link_change: true,
+        p3_link_change: true,
+        ..smi::Interrupts::NONE
+    };
+    smi.set_enabled_interrupts(enable).unwrap();
+    assert_eq!(
+        smi.interrupt_enable().read().unwrap().read().reg().bits(),
+        0b101
+    );
+    assert_eq!(smi.enabled_interrupts().unwrap(), enable);
+
+    smi.0
+         .0
+        .link_change_interrupt_mut()
+        .write()
+        .p1_p2()
+        .set_bit()
+        .p2()
+        .set_bit();
+    let pending = smi.interrupt_status().unwrap();
+    assert!(pending.p1_p2_link_change && pending.p2_link_change);
+    assert!(!pending.p1_link_change && !pending.p3_link_change);
+    assert_eq!(smi.clear_interrupts().unwrap(), pending);
+    assert_eq!(smi.interrupt_status().unwrap(), smi::Interrupts::NONE);
+    assert_eq!(u8::from(smi::Interrupts::ALL), 0b1000_0111);
+}
+
+#[test]
+fn port_set_enabled() {
+    let mut smi = Smi(smi::Map::default());
+    smi.0
+        .port3_ctrl2_mut()
+        .write()
+        .ingress_vlan_filtering()
+        .set_bit();
+    for &id in smi::PortId::ALL {
+        assert!(smi.port(id).is_enabled().unwrap());
+        smi.port(id).set_enabled(false).unwrap();
+        assert!(!smi.port(id).is_enabled().unwrap());
+    }
+    let ctrl2 = smi.0.port1_ctrl2().read();
+    assert!(ctrl2.transmit().bit_is_clear());
+    assert!(ctrl2.receive().bit_is_clear());
+    let ctrl2 = smi.0.port3_ctrl2().read();
+    assert!(ctrl2.transmit().bit_is_clear());
+    assert!(ctrl2.receive().bit_is_clear());
+    assert!(ctrl2.ingress_vlan_filtering().bit_is_set());
+
+    smi.port(smi::PortId::Port2).set_enabled(true).unwrap();
+    assert!(smi.0.port2_ctrl2().read().transmit().bit_is_set());
+    assert!(smi.0.port2_ctrl2().read().receive().bit_is_set());
+    assert!(!smi.port(smi::PortId::Port1).is_enabled().unwrap());
+}
+
+#[test]
+fn address_index() {
+    for (i, &addr) in smi::Address::ALL.iter().enumerate() {
+        assert_eq!(addr.index(), i);
+    }
+    assert_eq!(smi::Address::ALL.len(), smi::Map::LEN);
+}
+
+#[test]
+fn port3_force_flow_control() {
+    let mut smi = Smi(smi::Map::default());
+    smi.port3_ctrl2()
+        .modify(|w| w.force_flow_control().set_bit())
+        .unwrap();
+    let ctrl2 = smi.port3_ctrl2().read().unwrap();
+    assert!(ctrl2.read().force_flow_control().bit_is_set());
+    let bits: u8 = ctrl2.into();
+    assert_eq!(bits & (1 << 4), 1 << 4);
+    let ctrl2 = smi.port(smi::PortId::Port3).ctrl2().read().unwrap();
+    assert!(ctrl2.read().force_flow_control().bit_is_set());
+}
+
+#[test]
+fn config_apply() {
+    use smi::{PortConfig, PortId, PortSet};
+    let port2 = PortConfig {
+        learning: false,
+        vlan_membership: PortSet::from(0b101),
+        default_tag: smi::VlanTag::from(0x2005),
+        ..Default::default()
+    };
+    let port3 = PortConfig {
+        enabled: false,
+        ..Default::default()
+    };
+    let config = smi::Config::new()
+        .vlan(true)
+        .tail_tag(true)
+        .flow_control(smi::FlowControlConfig {
+            global_tx: true,
+            global_rx: false,
+            port_force: [true, false, false],
+        })
+        .port(PortId::Port2, port2)
+        .port(PortId::Port3, port3);
+
+    // Start from a device whose state differs from the defaults in an untouched register.
+    let mut device = smi::Map::default();
+    device.gc4_mut().write().mii_flow_ctrl().set_bit();
+    let mut smi = Smi(device);
+    config.apply(&mut smi).unwrap();
+    let map = smi.0;
+    assert!(map.gc3().read().vlan().bit_is_set());
+    assert!(map.gc1().read().port3_tail_tag().bit_is_set());
+    assert!(map.gc1().read().tx_flow_control().bit_is_set());
+    assert!(map.gc1().read().rx_flow_control().bit_is_clear());
+    assert!(map.gc4().read().mii_flow_ctrl().bit_is_set());
+    assert!(map.port1_ctrl2().read().force_flow_control().bit_is_set());
+    assert!(map.port2_ctrl2().read().learning_disable().bit_is_set());
+    assert!(map.port2_ctrl2().read().transmit().bit_is_set());
+    assert_eq!(
+        map.port2_ctrl1().read().port_vlan_membership().bits(),
+        0b101
+    );
+    assert_eq!(map.port2_ctrl3().read().default_tag_15_8().bits(), 0x20);
+    assert_eq!(map.port2_ctrl4().read().default_tag_7_0().bits(), 0x05);
+    assert!(map.port3_ctrl2().read().transmit().bit_is_clear());
+    assert!(map.port3_ctrl2().read().receive().bit_is_clear());
+}
+
+#[test]
+fn write_resets_untouched_fields() {
+    let mut device = smi::Map::default();
+    device
+        .gc1_mut()
+        .write()
+        .aging()
+        .clear_bit()
+        .fast_age()
+        .set_bit();
+    device.gc9_mut().write().bits(0b0100_0000);
+    let mut smi = Smi(device);
+
+    // `write` starts from the default state, so untouched fields are reset.
+    smi.gc1().write(|w| w.pass_all_frames().set_bit()).unwrap();
+    let gc1 = smi.0.gc1().read();
+    assert!(gc1.pass_all_frames().bit_is_set());
+    assert!(gc1.aging().bit_is_set());
+    assert!(gc1.fast_age().bit_is_clear());
+
+    // Reserved fields are written with their default value.
+    smi.gc9()
+        .write(|w| w.cpu_iface_clk().variant(smi::CpuIfaceClk::Mhz31_25))
+        .unwrap();
+    assert_eq!(smi.0.gc9().read().reserved().bits(), 0b10);
+
+    // `write_from` starts from the given state instead.
+    let mut initial = smi::Gc1::default();
+    initial.write().aging().clear_bit();
+    smi.gc1()
+        .write_from(initial, |w| w.fast_age().set_bit())
+        .unwrap();
+    let gc1 = smi.0.gc1().read();
+    assert!(gc1.pass_all_frames().bit_is_clear());
+    assert!(gc1.aging().bit_is_clear());
+    assert!(gc1.fast_age().bit_is_set());
+}
+
+// A mock SMI interface that counts the number of writes.
+#[derive(Default)]
+struct CountWrites {
+    map: smi::Map,
+    writes: usize,
+}
+
+impl smi::Read for CountWrites {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.map.read(reg_addr)
+    }
+}
+
+impl smi::Write for CountWrites {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.writes += 1;
+        self.map.write(reg_addr, data)
+    }
+}
+
+// A mock SMI interface that records the address of each write in order.
+#[derive(Default)]
+struct RecordWrites {
+    map: smi::Map,
+    addrs: Vec<u8>,
+}
+
+impl smi::Read for RecordWrites {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.map.read(reg_addr)
+    }
+}
+
+impl smi::Write for RecordWrites {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.addrs.push(reg_addr);
+        self.map.write(reg_addr, data)
+    }
+}
+
+// The registers that must never be written by a bulk write.
+const NO_BULK_WRITE: [smi::Address; 4] = [
+    smi::Address::IndirectAccessCtrl0,
+    smi::Address::IndirectAccessCtrl1,
+    smi::Address::Reset,
+    smi::Address::LinkChangeInterrupt,
+];
+
+#[test]
+fn modify_if_changed() {
+    let mut smi = Smi(CountWrites::default());
+    // Aging is enabled by default, so this is a no-op.
+    let written = smi
+        .gc1()
+        .modify_if_changed(|w| w.aging().set_bit())
+        .unwrap();
+    assert!(!written);
+    assert_eq!(smi.0.writes, 0);
+
+    let written = smi
+        .gc1()
+        .modify_if_changed(|w| w.aging().clear_bit())
+        .unwrap();
+    assert!(written);
+    assert_eq!(smi.0.writes, 1);
+    assert!(smi.0.map.gc1().read().aging().bit_is_clear());
+
+    let mut port = smi.port(smi::PortId::Port2);
+    assert!(!port
+        .ctrl2()
+        .modify_if_changed(|w| w.transmit().set_bit())
+        .unwrap());
+    assert!(port
+        .ctrl2()
+        .modify_if_changed(|w| w.transmit().clear_bit())
+        .unwrap());
+    assert_eq!(smi.0.writes, 2);
+}
+
+#[test]
+fn counting_transactions() {
+    let mut smi = Smi(smi::Counting::new(smi::Map::default()));
+    smi.gc1().read().unwrap();
+    smi.gc1().modify(|w| w.aging().clear_bit()).unwrap();
+    smi.gc1()
+        .modify_if_changed(|w| w.aging().clear_bit())
+        .unwrap();
+    smi.gc3().write(|w| w.vlan().set_bit()).unwrap();
+    assert_eq!(smi.0.reads(), 3);
+    assert_eq!(smi.0.writes(), 2);
+    smi.0.reset();
+    smi.read_map().unwrap();
+    assert_eq!(smi.0.reads(), smi::Map::LEN);
+    assert_eq!(smi.0.writes(), 0);
+    assert!(smi.0.inner().gc3().read().vlan().bit_is_set());
+}
+
+// A mock SMI interface that fails the given number of attempts before each success.
+struct Flaky {
+    map: smi::Map,
+    failures: usize,
+    remaining: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct Glitch;
+
+impl Flaky {
+    fn attempt(&mut self) -> Result<(), Glitch> {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            return Err(Glitch);
+        }
+        self.remaining = self.failures;
+        Ok(())
+    }
+}
+
+impl smi::Read for Flaky {
+    type Error = Glitch;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        self.attempt()?;
+        Ok(self.map.read(reg_addr).unwrap())
+    }
+}
+
+impl smi::Write for Flaky {
+    type Error = Glitch;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.attempt()?;
+        self.map.write(reg_addr, data).unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn retry_flaky_interface() {
+    let flaky = Flaky {
+        map: smi::Map::default(),
+        failures: 2,
+        remaining: 2,
+    };
+    let mut smi = Smi(smi::Retry::new(flaky, 2));
+    smi.gc1().modify(|w| w.aging().clear_bit()).unwrap();
+    assert!(smi.gc1().read().unwrap().read().aging().bit_is_clear());
+
+    smi.0.inner_mut().failures = 3;
+    smi.0.inner_mut().remaining = 3;
+    assert_eq!(smi.gc1().read().unwrap_err(), Glitch);
+}
+
+#[test]
+fn smi_read_write_range() {
+    let mut smi = Smi(smi::Map::default());
+    let mac_addr = [0x02, 0x11, 0x22, 0x33, 0x44, 0x55];
+    smi.write_range(smi::Address::MacAddr0, &mac_addr).unwrap();
+    let mut out = [0u8; 6];
+    smi.read_range(smi::Address::MacAddr0, &mut out).unwrap();
+    assert_eq!(out, mac_addr);
+    assert_eq!(smi.mac_addr().unwrap(), mac_addr);
+
+    // `Gc5` at `0x07` is followed by a gap before `Gc9` at `0x0B`.
+    let mut out = [0u8; 2];
+    match smi.read_range(smi::Address::Gc5, &mut out) {
+        Err(ksz8863::Error::InvalidAddress) => (),
+        _ => panic!("expected invalid address"),
+    }
+    // The run may not extend past the last register.
+    match smi.write_range(smi::Address::FwdInvalidVidFrameAndHostMode, &[0, 0]) {
+        Err(ksz8863::Error::InvalidAddress) => (),
+        _ => panic!("expected invalid address"),
+    }
+}
+
+#[test]
+fn dscp_priority() {
+    let mut smi = Smi(smi::Map::default());
+    smi.set_dscp_priority(0, 0b01).unwrap();
+    smi.set_dscp_priority(3, 0b11).unwrap();
+    smi.set_dscp_priority(46, 0b10).unwrap();
+    smi.set_dscp_priority(63, 0b11).unwrap();
+    assert_eq!(smi.dscp_priority(0).unwrap(), 0b01);
+    assert_eq!(smi.dscp_priority(1).unwrap(), 0);
+    assert_eq!(smi.dscp_priority(3).unwrap(), 0b11);
+    assert_eq!(smi.dscp_priority(46).unwrap(), 0b10);
+    assert_eq!(smi.dscp_priority(63).unwrap(), 0b11);
+    assert_eq!(
+        smi.0.tos_priority_ctrl_0().read().dscp0_7().bits(),
+        0b1100_0001
+    );
+    // DSCP 46 is the third entry of `TosPriorityCtrl11`.
+    let ctrl11: u8 = (*smi.0.tos_priority_ctrl_11()).into();
+    assert_eq!(ctrl11, 0b0010_0000);
+    let ctrl15: u8 = (*smi.0.tos_priority_ctrl_15()).into();
+    assert_eq!(ctrl15, 0b1100_0000);
+
+    match smi.dscp_priority(64) {
+        Err(smi::DscpError::InvalidDscp) => (),
+        _ => panic!("expected invalid DSCP"),
+    }
+    match smi.set_dscp_priority(0, 4) {
+        Err(smi::DscpError::InvalidPriority) => (),
+        _ => panic!("expected invalid priority"),
+    }
+}
+
+#[test]
+fn port_far_end_fault() {
+    let mut smi = Smi(smi::Map::default());
+    assert!(!smi.port(smi::PortId::Port1).far_end_fault().unwrap());
+    smi.0.port2_status1_mut().write().bits(0b0000_0001);
+    assert!(!smi.port(smi::PortId::Port1).far_end_fault().unwrap());
+    assert!(smi.port(smi::PortId::Port2).far_end_fault().unwrap());
+    match smi.port(smi::PortId::Port3).far_end_fault() {
+        Err(smi::PortError::UnsupportedRegister) => (),
+        _ => panic!("expected unsupported register"),
+    }
+
+    smi.port(smi::PortId::Port1)
+        .set_far_end_fault_detection(false)
+        .unwrap();
+    let ctrl13 = smi.0.port1_ctrl13().read();
+    assert!(ctrl13.disable_far_end_fault().bit_is_set());
+    smi.port(smi::PortId::Port1)
+        .set_far_end_fault_detection(true)
+        .unwrap();
+    let ctrl13 = smi.0.port1_ctrl13().read();
+    assert!(ctrl13.disable_far_end_fault().bit_is_clear());
+}
+
+#[test]
+fn port_set_loopback() {
+    use smi::LoopbackMode;
+    let mut smi = Smi(smi::Map::default());
+    let mut port = smi.port(smi::PortId::Port2);
+    port.set_loopback(LoopbackMode::FarEnd).unwrap();
+    port.set_loopback(LoopbackMode::Remote).unwrap();
+    assert!(smi.0.port2_ctrl13().read().loopback().bit_is_clear());
+    assert!(smi
+        .0
+        .port2_phy_special()
+        .read()
+        .remote_loopback()
+        .bit_is_set());
+
+    let mut port = smi.port(smi::PortId::Port2);
+    port.set_loopback(LoopbackMode::FarEnd).unwrap();
+    assert!(smi.0.port2_ctrl13().read().loopback().bit_is_set());
+    assert!(smi
+        .0
+        .port2_phy_special()
+        .read()
+        .remote_loopback()
+        .bit_is_clear());
+
+    let mut port = smi.port(smi::PortId::Port2);
+    port.set_loopback(LoopbackMode::Disabled).unwrap();
+    assert!(smi.0.port2_ctrl13().read().loopback().bit_is_clear());
+    assert!(smi
+        .0
+        .port2_phy_special()
+        .read()
+        .remote_loopback()
+        .bit_is_clear());
+
+    match smi
+        .port(smi::PortId::Port3)
+        .set_loopback(LoopbackMode::FarEnd)
+    {
+        Err(smi::PortError::UnsupportedRegister) => (),
+        _ => panic!("expected unsupported register"),
+    }
+}
+
+#[test]
+fn smi_read_status() {
+    let mut device = smi::Map::default();
+    device.port1_status0_mut().write().bits(0b0110_0000);
+    device.port1_status1_mut().write().bits(0b0000_0110);
+    device.port3_status1_mut().write().bits(0b0001_0100);
+    let mut smi = Smi(smi::Counting::new(device));
+    let status = smi.read_status().unwrap();
+    assert_eq!(smi.0.reads(), 5);
+    assert_eq!(smi.0.writes(), 0);
+
+    assert!(status.port1.up);
+    assert!(status.port1.an_done);
+    assert_eq!(status.port1.speed, smi::Speed::Mbps100);
+    assert_eq!(status.port1.duplex, smi::Duplex::Full);
+    assert!(!status.port2.up);
+    assert_eq!(status.port2.speed, smi::Speed::Mbps10);
+    assert_eq!(status.port3.speed, smi::Speed::Mbps100);
+    assert_eq!(status.port3.duplex, smi::Duplex::Half);
+    assert!(status.port3.flow_control.tx);
+    assert!(!status.port3.flow_control.rx);
+}
+
+#[test]
+fn tail_tag() {
+    use smi::{PortId, PortSet};
+    assert_eq!(smi::encode_tail_tag(PortSet::EMPTY), 0b00);
+    assert_eq!(smi::encode_tail_tag(PortSet::single(PortId::Port1)), 0b01);
+    assert_eq!(smi::encode_tail_tag(PortSet::single(PortId::Port2)), 0b10);
+    assert_eq!(smi::encode_tail_tag(PortSet::ALL), 0b11);
+    assert_eq!(smi::decode_tail_tag(0b00).source, PortId::Port1);
+    assert_eq!(smi::decode_tail_tag(0b01).source, PortId::Port2);
+
+    let mut smi = Smi(smi::Map::default());
+    smi.set_tail_tag(true).unwrap();
+    assert!(smi.0.gc1().read().port3_tail_tag().bit_is_set());
+    assert!(smi.0.gc1().read().aging().bit_is_set());
+    smi.set_tail_tag(false).unwrap();
+    assert!(smi.0.gc1().read().port3_tail_tag().bit_is_clear());
+}
+
+#[test]
+fn force_field_defaults() {
+    let ctrl12: u8 = smi::Port1Ctrl12::default().into();
+    assert_eq!(ctrl12, 0xFF);
+
+    let mut ctrl12 = smi::Port2Ctrl12::from(0x00);
+    ctrl12.write().reset();
+    assert!(ctrl12.read().an_enable().bit_is_set());
+    assert!(ctrl12.read().force_speed().bit_is_set());
+    assert!(ctrl12.read().force_duplex().bit_is_set());
+
+    let mut ctrl12 = smi::Port1Ctrl12::from(0x00);
+    ctrl12.write().an_enable().reset().force_duplex().reset();
+    assert!(ctrl12.read().an_enable().bit_is_set());
+    assert!(ctrl12.read().force_speed().bit_is_clear());
+    assert!(ctrl12.read().force_duplex().bit_is_set());
+
+    let mut ctrl2 = smi::Port3Ctrl2::from(0xFF);
+    ctrl2.write().force_flow_control().reset();
+    assert!(ctrl2.read().force_flow_control().bit_is_clear());
+    let mut ctrl2 = smi::Port1Ctrl2::from(0xFF);
+    ctrl2.write().reset();
+    assert!(ctrl2.read().force_flow_control().bit_is_clear());
+}
+
+#[test]
+fn smi_frame_commands() {
+    use smi::frame;
+    // ST `01`, OP `00`, PHYAD `1x` + REGAD holding the 8-bit register address, TA `Z0`.
+    assert_eq!(frame::read_command(0x01), [0b0100_1000, 0b0000_0100]);
+    assert_eq!(frame::read_command(0xFF), [0b0100_1011, 0b1111_1100]);
+    // ST `01`, OP `00`, PHYAD `0x` + REGAD holding the 8-bit register address, TA `10`, data.
+    assert_eq!(
+        frame::write_command(0x01, 0x5A),
+        [0b0100_0000, 0b0000_0110, 0x00, 0x5A]
+    );
+    assert_eq!(
+        frame::write_command(0x80, 0xFF),
+        [0b0100_0010, 0b0000_0010, 0x00, 0xFF]
+    );
+    assert_eq!(frame::read_data([0x00, 0xA5]), 0xA5);
+    assert_eq!(frame::PREAMBLE, [0xFF; 4]);
+    assert_eq!(
+        u16::from_be_bytes(frame::read_command(0x43)),
+        smi::read_ctrl_bits(0x43)
+    );
+}
+
+// A mock SMI interface that clears the `Gc0` flush bits after the given number of reads.
+struct AutoClearFlush {
+    map: smi::Map,
+    reads_until_clear: usize,
+}
+
+impl smi::Read for AutoClearFlush {
+    type Error = ksz8863::InvalidAddress;
+    fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+        if reg_addr == u8::from(smi::Address::Gc0) && self.reads_until_clear > 0 {
+            self.reads_until_clear -= 1;
+            if self.reads_until_clear == 0 {
+                let gc0: u8 = (*self.map.gc0()).into();
+                *self.map.gc0_mut() = smi::Gc0::from(gc0 & !0b0011_0000);
+            }
+        }
+        self.map.read(reg_addr)
+    }
+}
+
+impl smi::Write for AutoClearFlush {
+    type Error = ksz8863::InvalidAddress;
+    fn write(&mut self, reg_addr: u8, data: u8) -> Result<(), Self::Error> {
+        self.map.write(reg_addr, data)
+    }
+}
+
+#[test]
+fn flush_mac_tables() {
+    let mut smi = Smi(AutoClearFlush {
+        map: smi::Map::default(),
+        reads_until_clear: 3,
+    });
+    smi.flush_dynamic_mac_table(4).unwrap();
+    assert!(smi
+        .0
+        .map
+        .gc0()
+        .read()
+        .flush_dynamic_mac_table()
+        .bit_is_clear());
+
+    smi.0.reads_until_clear = 8;
+    match smi.flush_static_mac_table(4) {
+        Err(ksz8863::PollError::Timeout) => (),
+        _ => panic!("expected timeout"),
+    }
+    assert!(smi.0.map.gc0().read().flush_static_mac_table().bit_is_set());
+
+    smi.set_aging(false).unwrap();
+    smi.set_fast_age(true).unwrap();
+    let gc1 = smi.0.map.gc1().read();
+    assert!(gc1.aging().bit_is_clear());
+    assert!(gc1.fast_age().bit_is_set());
+}
+
+#[test]
+fn smi_set_mirroring() {
+    use smi::{Direction, MirrorConfig, PortId};
+    let mut map = smi::Map::default();
+    // A stale sniffer bit on another port should be cleared.
+    map.port1_ctrl1_mut().write().sniffer_port().set_bit();
+    let mut smi = Smi(map);
+    let config = MirrorConfig::new(PortId::Port3)
+        .mirror(PortId::Port1, Direction::Rx)
+        .mirror(PortId::Port2, Direction::Both);
+    smi.set_mirroring(config).unwrap();
+
+    let p1 = smi.0.port1_ctrl1().read();
+    assert!(p1.sniffer_port().bit_is_clear());
+    assert!(p1.receive_sniff().bit_is_set());
+    assert!(p1.transmit_sniff().bit_is_clear());
+    let p2 = smi.0.port2_ctrl1().read();
+    assert!(p2.sniffer_port().bit_is_clear());
+    assert!(p2.receive_sniff().bit_is_set());
+    assert!(p2.transmit_sniff().bit_is_set());
+    let p3 = smi.0.port3_ctrl1().read();
+    assert!(p3.sniffer_port().bit_is_set());
+    assert!(p3.receive_sniff().bit_is_clear());
+    assert!(p3.transmit_sniff().bit_is_clear());
+}
+
+#[test]
+fn smi_map_checksum() {
+    let mut map = smi::Map::default();
+    let checksum = map.checksum();
+    assert_eq!(checksum, smi::Map::default().checksum());
+    map.gc2_mut().write().huge_packet_support().set_bit();
+    assert_ne!(map.checksum(), checksum);
+    map.gc2_mut().write().huge_packet_support().clear_bit();
+    assert_eq!(map.checksum(), checksum);
+}
+
+#[test]
+fn map_try_from_slice() {
+    use std::convert::TryFrom;
+    let mut map = smi::Map::default();
+    map.gc3_mut().write().vlan().set_bit();
+    let bytes = map.to_bytes();
+    let decoded = smi::Map::try_from(&bytes[..]).unwrap();
+    assert_eq!(decoded, map);
+    assert!(smi::Map::try_from(&bytes[1..]).is_err());
+    let mut long = bytes.to_vec();
+    long.push(0);
+    assert!(smi::Map::try_from(&long[..]).is_err());
+    assert!(smi::Map::try_from(&[][..]).is_err());
+}
+
+#[test]
+fn smi_read_raw() {
+    let mut map = smi::Map::default();
+    map.gc3_mut().write().vlan().set_bit();
+    let mut smi = Smi(map);
+    let state = smi.read_raw(0x05).unwrap();
+    assert_eq!(state.addr(), smi::Address::Gc3);
+    assert_eq!(state, smi::State::from(*smi.0.gc3()));
+    match smi.read_raw(0xFF) {
+        Err(ksz8863::Error::InvalidAddress) => (),
+        _ => panic!("expected invalid address"),
+    }
+}
+
+// An SMI interface on which every access fails.
+struct Faulty;
+
+#[derive(Debug, PartialEq)]
+struct BusFault;
+
+impl smi::Read for Faulty {
+    type Error = BusFault;
+    fn read(&mut self, _reg_addr: u8) -> Result<u8, Self::Error> {
+        Err(BusFault)
+    }
+}
+
+#[test]
+fn error_variants() {
+    let mut smi = Smi(Faulty);
+    match smi.read_raw(0x05) {
+        Err(ksz8863::Error::Interface(BusFault)) => (),
+        _ => panic!("expected interface error"),
+    }
+    match smi.read_raw(0xFF) {
+        Err(ksz8863::Error::InvalidAddress) => (),
+        _ => panic!("expected invalid address"),
+    }
+    let err: ksz8863::Error<BusFault> = ksz8863::InvalidAddress.into();
+    assert!(matches!(err, ksz8863::Error::InvalidAddress));
+}
+
+#[test]
+fn register_is_default() {
+    let mut map = smi::Map::default();
+    assert!(map.gc1().is_default());
+    assert!(map.iter().all(|(_, state)| state.is_default()));
+    map.gc1_mut().write().aging().clear_bit();
+    assert!(!map.gc1().is_default());
+    assert!(!map.state(smi::Address::Gc1).is_default());
+    assert!(map.state(smi::Address::Gc2).is_default());
+}
+
+#[test]
+fn smi_cpu_port_config() {
+    use smi::{CpuPortConfig, MiiMode, RmiiClock};
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.cpu_port_config().unwrap(), CpuPortConfig::default());
+    let config = CpuPortConfig {
+        mii_mode: MiiMode::Phy,
+        rmii_clock: RmiiClock::Internal,
+        refclk_invert: true,
+    };
+    smi.set_cpu_port_config(config).unwrap();
+    assert!(smi
+        .0
+        .port3_ctrl5()
+        .read()
+        .port3_mii_mode_selection()
+        .bit_is_set());
+    assert!(smi
+        .0
+        .fwd_invalid_vid_frame_and_host_mode()
+        .read()
+        .p3_rmii_clock_selection()
+        .bit_is_set());
+    let q0 = smi.0.port3_q0_ingress_rate_limit().read();
+    assert!(q0.rmii_refclk_invert().bit_is_set());
+    assert_eq!(q0.limit().bits(), 0);
+    // Neighbouring bits are untouched.
+    assert!(smi
+        .0
+        .fwd_invalid_vid_frame_and_host_mode()
+        .read()
+        .p1_rmii_clock_selection()
+        .bit_is_clear());
+    assert!(smi
+        .0
+        .port1_ctrl5()
+        .read()
+        .port3_mii_mode_selection()
+        .bit_is_clear());
+    assert_eq!(smi.cpu_port_config().unwrap(), config);
+}
+
+#[test]
+fn address_display() {
+    assert_eq!(smi::Address::Gc1.name(), "Gc1");
+    assert_eq!(smi::Address::Port3Ctrl5.to_string(), "Port3Ctrl5");
+    assert_eq!(format!("{}", smi::Address::MacAddr0), "MacAddr0");
+}
+
+#[cfg(feature = "ufmt")]
+#[test]
+fn address_udisplay() {
+    struct Buf(heapless::String<32>);
+    impl ufmt::uWrite for Buf {
+        type Error = ();
+        fn write_str(&mut self, s: &str) -> Result<(), ()> {
+            self.0.push_str(s)
+        }
+    }
+    let mut buf = Buf(heapless::String::new());
+    ufmt::uwrite!(buf, "{}", smi::Address::Port3Ctrl5).unwrap();
+    assert_eq!(buf.0.as_str(), "Port3Ctrl5");
+}
+
+#[test]
+fn address_from_str() {
+    assert_eq!("Gc1".parse::<smi::Address>().unwrap(), smi::Address::Gc1);
+    assert!("gc1".parse::<smi::Address>().is_err());
+    assert!("NotARegister".parse::<smi::Address>().is_err());
+    for &addr in smi::Address::ALL {
+        assert_eq!(addr.to_string().parse::<smi::Address>().unwrap(), addr);
+    }
+}
+
+#[test]
+fn address_from_u8_const() {
+    const GC1: Option<smi::Address> = smi::Address::from_u8(0x03);
+    const NONE: Option<smi::Address> = smi::Address::from_u8(0xFF);
+    assert_eq!(GC1, Some(smi::Address::Gc1));
+    assert_eq!(NONE, None);
+}
+
+#[test]
+fn smi_set_fiber_mode() {
+    use ksz8863::link::Duplex;
+    use smi::{FiberConfig, FiberThreshold, PortId};
+    let mut smi = Smi(smi::Map::default());
+    let config = FiberConfig {
+        threshold: FiberThreshold::High,
+        duplex: Duplex::Half,
+        far_end_fault_detection: true,
+    };
+    smi.set_fiber_mode(PortId::Port2, config).unwrap();
+    let threshold = smi.0.fiber_signal_threshold().read();
+    assert!(threshold.port2().bit_is_set());
+    assert!(threshold.port1().bit_is_clear());
+    let ctrl12 = smi.0.port2_ctrl12().read();
+    assert!(ctrl12.an_enable().bit_is_clear());
+    assert!(ctrl12.force_speed().bit_is_set());
+    assert!(ctrl12.force_duplex().bit_is_clear());
+    assert!(smi
+        .0
+        .port2_ctrl13()
+        .read()
+        .disable_far_end_fault()
+        .bit_is_clear());
+    // Port 1 is untouched.
+    assert!(smi.0.port1_ctrl12().read().an_enable().bit_is_set());
+
+    smi.set_fiber_mode(PortId::Port1, FiberConfig::default())
+        .unwrap();
+    let threshold = smi.0.fiber_signal_threshold().read();
+    assert!(threshold.port1().bit_is_clear());
+    assert!(threshold.port2().bit_is_set());
+
+    match smi.set_fiber_mode(PortId::Port3, config) {
+        Err(smi::PortError::UnsupportedRegister) => (),
+        _ => panic!("expected unsupported register"),
+    }
+}
+
+#[test]
+fn smi_insert_src_pvid_matrix() {
+    use smi::{InsertSrcPvidMatrix, PortId};
+    let mut smi = Smi(smi::Map::default());
+    let mut matrix = InsertSrcPvidMatrix::NONE;
+    matrix.set(PortId::Port1, PortId::Port3, true);
+    matrix.set(PortId::Port3, PortId::Port2, true);
+    // A port has no bit for itself.
+    matrix.set(PortId::Port2, PortId::Port2, true);
+    assert!(!matrix.get(PortId::Port2, PortId::Port2));
+    assert!(matrix.get(PortId::Port1, PortId::Port3));
+    assert!(!matrix.get(PortId::Port3, PortId::Port1));
+    smi.set_insert_src_pvid_matrix(matrix).unwrap();
+
+    let reg = smi.0.insert_src_pvid().read();
+    assert_eq!(reg.bits(), 0b0001_0001);
+    assert!(reg.p1_at_p3().bit_is_set());
+    assert!(reg.p3_at_p2().bit_is_set());
+    assert_eq!(smi.insert_src_pvid_matrix().unwrap(), matrix);
+
+    matrix.set(PortId::Port1, PortId::Port3, false);
+    assert_eq!(matrix.bits(), 0b0000_0001);
+}
+
+#[test]
+fn write_bits_masked() {
+    let mut gc1 = smi::Gc1::from(0b1010_1010);
+    gc1.write().bits_masked(0b0101_1111, 0b0000_1111);
+    let bits: u8 = gc1.into();
+    assert_eq!(bits, 0b1010_1111);
+    gc1.write().bits_masked(0xFF, 0);
+    let bits: u8 = gc1.into();
+    assert_eq!(bits, 0b1010_1111);
+}
+
+#[cfg(feature = "trace")]
+#[test]
+fn smi_traced() {
+    use ksz8863::TransactionKind;
+    use smi::{Traced, Transaction};
+    let mut log = heapless::Vec::<Transaction, 8>::new();
+    {
+        let traced = Traced::new(smi::Map::default(), |t| log.push(t).unwrap());
+        let mut smi = Smi(traced);
+        smi.gc3().modify(|w| w.vlan().set_bit()).unwrap();
+    }
+    let expected = [
+        Transaction {
+            kind: TransactionKind::Read,
+            reg_addr: 0x05,
+            data: 0x00,
+        },
+        Transaction {
+            kind: TransactionKind::Write,
+            reg_addr: 0x05,
+            data: 0x80,
+        },
+    ];
+    assert_eq!(&log[..], &expected[..]);
+}
+
+#[test]
+fn rate_limit_conversions() {
+    use smi::RateLimit;
+    use std::convert::TryFrom;
+    // Datasheet table entries.
+    assert_eq!(RateLimit::from_kbps(100_000), RateLimit::UNLIMITED);
+    assert_eq!(RateLimit::UNLIMITED.to_kbps(), 100_000);
+    assert_eq!(RateLimit::from_kbps(1_000).code(), 1);
+    assert_eq!(RateLimit::from_kbps(50_000).code(), 50);
+    assert_eq!(RateLimit::from_kbps(99_000).code(), 99);
+    assert_eq!(RateLimit::from_kbps(64).code(), 101);
+    assert_eq!(RateLimit::from_kbps(512).code(), 108);
+    assert_eq!(RateLimit::from_kbps(960).code(), 115);
+    assert_eq!(RateLimit::try_from(115).unwrap().to_kbps(), 960);
+    assert_eq!(RateLimit::try_from(100).unwrap().to_kbps(), 100_000);
+    assert_eq!(RateLimit::try_from(1).unwrap().to_kbps(), 1_000);
+    assert!(RateLimit::try_from(116).is_err());
+    // Rounding.
+    assert_eq!(RateLimit::from_kbps(1_999).code(), 1);
+    assert_eq!(RateLimit::from_kbps(10).code(), 101);
+    for code in 0..=115 {
+        let limit = RateLimit::try_from(code).unwrap();
+        if code != 100 {
+            assert_eq!(RateLimit::from_kbps(limit.to_kbps()), limit);
+        }
+    }
+}
+
+#[test]
+fn port_set_ingress_rate_limit() {
+    use smi::{PortId, Queue, RateLimit};
+    let mut map = smi::Map::default();
+    map.port3_q0_ingress_rate_limit_mut()
+        .write()
+        .rmii_refclk_invert()
+        .set_bit();
+    let mut smi = Smi(map);
+    let limit = RateLimit::from_kbps(20_000);
+    smi.port(PortId::Port3)
+        .set_ingress_rate_limit(Queue::Q0, limit)
+        .unwrap();
+    smi.port(PortId::Port1)
+        .set_ingress_rate_limit(Queue::Q2, RateLimit::from_kbps(128))
+        .unwrap();
+    let q0 = smi.0.port3_q0_ingress_rate_limit().read();
+    assert_eq!(q0.limit().bits(), 20);
+    assert!(q0.rmii_refclk_invert().bit_is_set());
+    assert_eq!(
+        smi.0.port1_q2_ingress_rate_limit().read().limit().bits(),
+        102
+    );
+    assert_eq!(smi.0.port1_q0_ingress_rate_limit().read().limit().bits(), 0);
+}
+
+#[test]
+fn port_set_egress_rate_limit() {
+    use smi::{PortId, Queue, RateLimit};
+    let mut map = smi::Map::default();
+    map.port2_q1_egress_rate_limit_mut().write().bits(0x80);
+    let mut smi = Smi(map);
+    smi.port(PortId::Port1)
+        .set_egress_rate_limit(Queue::Q0, RateLimit::from_kbps(5_000))
+        .unwrap();
+    smi.port(PortId::Port2)
+        .set_egress_rate_limit(Queue::Q1, RateLimit::from_kbps(64))
+        .unwrap();
+    smi.port(PortId::Port3)
+        .set_egress_rate_limit(Queue::Q3, RateLimit::from_kbps(50_000))
+        .unwrap();
+    assert_eq!(u8::from(smi::Address::Port1Q0EgressRateLimit), 0x9A);
+    assert_eq!(u8::from(smi::Address::Port3Q3EgressRateLimit), 0xA5);
+    assert_eq!(smi.0.port1_q0_egress_rate_limit().read().limit().bits(), 5);
+    // Bits outside of the limit field are preserved.
+    assert_eq!(smi.0.port2_q1_egress_rate_limit().read().bits(), 0x80 | 101);
+    assert_eq!(smi.0.port3_q3_egress_rate_limit().read().limit().bits(), 50);
+    assert_eq!(smi.0.port3_q2_egress_rate_limit().read().limit().bits(), 0);
+    // Ingress limits are untouched.
+    assert_eq!(smi.0.port1_q0_ingress_rate_limit().read().limit().bits(), 0);
+}
+
+#[test]
+fn smi_set_start_switch() {
+    let mut map = smi::Map::default();
+    // Chip ID `0x8`, revision ID `0x5` and started.
+    map.chip_id1_mut().write().bits(0b1000_1011);
+    let mut smi = Smi(map);
+    smi.set_start_switch(false).unwrap();
+    assert_eq!(smi.0.chip_id1().read().bits(), 0b1000_1010);
+    smi.set_start_switch(true).unwrap();
+    assert_eq!(smi.0.chip_id1().read().bits(), 0b1000_1011);
+}
+
+#[test]
+fn map_eq_writable() {
+    let desired = smi::Map::default();
+    let mut device = desired.clone();
+    // Status bits differ.
+    device.port1_status0_mut().write().bits(0xFF);
+    device.chip_id1_mut().write().bits(0b0011_1011);
+    assert_ne!(device, desired);
+    assert!(device.eq_writable(&desired));
+    assert!(device
+        .state(smi::Address::Port1Status0)
+        .eq_writable(desired.state(smi::Address::Port1Status0)));
+
+    // A writable bit differs.
+    device.gc1_mut().write().aging().clear_bit();
+    assert!(!device.eq_writable(&desired));
+    // States of different registers are never equal.
+    let gc2 = *desired.state(smi::Address::Gc2);
+    assert!(!desired.state(smi::Address::Gc3).eq_writable(&gc2));
+}
+
+#[test]
+fn mode_variant() {
+    use smi::ChipVariant;
+    let mut map = smi::Map::default();
+    map.mode_mut().write().bits(0x07);
+    let variant = map.mode().variant().unwrap();
+    assert_eq!(variant, ChipVariant::Rll);
+    assert!(variant.is_rmii());
+    assert!(!variant.supports_fiber());
+    map.mode_mut().write().bits(0x53);
+    assert_eq!(map.mode().variant().unwrap(), ChipVariant::Fll);
+    assert!(ChipVariant::Fll.supports_fiber());
+    assert_eq!(u8::from(ChipVariant::Mll), 0x43);
+    map.mode_mut().write().bits(0xFF);
+    assert!(map.mode().variant().is_err());
+}
+
+#[test]
+fn smi_high_priority_buffer() {
+    use smi::Queue;
+    let mut smi = Smi(smi::Map::default());
+    let defaults = [
+        (Queue::Q0, 0x15),
+        (Queue::Q1, 0x25),
+        (Queue::Q2, 0x35),
+        (Queue::Q3, 0x45),
+    ];
+    for &(queue, value) in &defaults {
+        assert_eq!(smi.high_priority_buffer(queue).unwrap(), value);
+    }
+    smi.set_high_priority_buffer(Queue::Q3, 0x10).unwrap();
+    smi.set_high_priority_buffer(Queue::Q0, 0x20).unwrap();
+    assert_eq!(
+        smi.0.high_priority_packet_buffer_q3().read().data().bits(),
+        0x10
+    );
+    assert_eq!(
+        smi.0.high_priority_packet_buffer_q0().read().data().bits(),
+        0x20
+    );
+    assert_eq!(smi.high_priority_buffer(Queue::Q1).unwrap(), 0x25);
+    assert_eq!(smi.high_priority_buffer(Queue::Q3).unwrap(), 0x10);
+}
+
+#[test]
+fn smi_sleep_timer() {
+    use smi::PwrMgmtMode;
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.sleep_timer().unwrap(), 0x50);
+    smi.set_sleep_timer(0x10).unwrap();
+    assert_eq!(smi.sleep_timer().unwrap(), 0x10);
+    assert_eq!(smi.0.sleep_mode().read().data().bits(), 0x10);
+
+    smi.pwr_mgmt_and_led_mode()
+        .modify(|w| w.led_output_mode().set_bit())
+        .unwrap();
+    smi.set_energy_detect_mode(0x20).unwrap();
+    assert_eq!(smi.sleep_timer().unwrap(), 0x20);
+    let pwr = smi.0.pwr_mgmt_and_led_mode().read();
+    assert_eq!(
+        pwr.pwr_mgmt_mode().variant().unwrap(),
+        PwrMgmtMode::EnergyDetect
+    );
+    assert!(pwr.led_output_mode().bit_is_set());
+}
+
+#[test]
+fn smi_pause_iteration_limit() {
+    let mut smi = Smi(smi::Map::default());
+    assert!(!smi.pause_iteration_limit().unwrap());
+    smi.set_pause_iteration_limit(true).unwrap();
+    assert_eq!(smi.0.force_pause_off().read().bits(), 0x01);
+    assert!(smi.pause_iteration_limit().unwrap());
+    smi.set_pause_iteration_limit(false).unwrap();
+    assert_eq!(smi.0.force_pause_off().read().bits(), 0x00);
+
+    // A set reserved bit does not read as enabled, and is preserved on write.
+    let mut device = smi::Map::default();
+    device.force_pause_off_mut().write().bits(0x80);
+    let mut smi = Smi(device);
+    assert!(!smi.pause_iteration_limit().unwrap());
+    smi.set_pause_iteration_limit(true).unwrap();
+    assert_eq!(smi.0.force_pause_off().read().bits(), 0x81);
+}
+
+#[test]
+fn smi_clone() {
+    let mut a = Smi(smi::Map::default());
+    let b = a.clone();
+    assert_eq!(a, b);
+    a.gc3().modify(|w| w.vlan().set_bit()).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(Smi::<smi::Map>::default(), b);
+}
+
+#[test]
+fn smi_sim() {
+    use smi::PortId;
+    let mut smi = Smi(smi::Sim::default());
+    smi.gc3().modify(|w| w.vlan().set_bit()).unwrap();
+    smi.software_reset().unwrap();
+    assert_eq!(smi.0.map(), &smi::Map::default());
+
+    smi.flush_dynamic_mac_table(1).unwrap();
+    smi.flush_static_mac_table(1).unwrap();
+
+    smi.port(PortId::Port2).restart_an_and_wait(1).unwrap();
+    let map = smi.0.map();
+    assert!(map.port2_ctrl13().read().restart_an().bit_is_clear());
+    assert!(map.port2_status0().read().an_done().bit_is_set());
+    assert!(map.port1_status0().read().an_done().bit_is_clear());
+}
+
+#[test]
+fn port_force_link() {
+    use smi::{Duplex, PortId, Speed};
+    let mut smi = Smi(smi::Map::default());
+    smi.port(PortId::Port1)
+        .force_link(Speed::Mbps10, Duplex::Full)
+        .unwrap();
+    let ctrl12 = smi.0.port1_ctrl12().read();
+    assert!(ctrl12.an_enable().bit_is_clear());
+    assert!(ctrl12.force_speed().bit_is_clear());
+    assert!(ctrl12.force_duplex().bit_is_set());
+    assert!(ctrl12.adv_100_fd().bit_is_set());
+    assert!(smi.0.port2_ctrl12().read().an_enable().bit_is_set());
+    match smi
+        .port(PortId::Port3)
+        .force_link(Speed::Mbps100, Duplex::Half)
+    {
+        Err(smi::PortError::UnsupportedRegister) => (),
+        _ => panic!("expected unsupported register"),
+    }
+}
+
+#[test]
+fn port_set_power_down() {
+    use smi::PortId;
+    let mut smi = Smi(smi::Map::default());
+    smi.port(PortId::Port2).set_power_down(true).unwrap();
+    assert!(smi.0.port2_ctrl13().read().power_down().bit_is_set());
+    assert!(smi.0.port1_ctrl13().read().power_down().bit_is_clear());
+    smi.port(PortId::Port2).set_power_down(false).unwrap();
+    assert!(smi.0.port2_ctrl13().read().power_down().bit_is_clear());
+    assert!(smi.port(PortId::Port3).set_power_down(true).is_err());
+}
+
+#[test]
+fn smi_write_diff() {
+    let from = smi::Map::default();
+    let mut to = from.clone();
+    to.gc3_mut().write().vlan().set_bit();
+    to.port2_ctrl1_mut().write().sniffer_port().set_bit();
+    // Read-only differences are skipped.
+    to.port1_status0_mut().write().bits(0xFF);
+
+    let mut smi = Smi(smi::Counting::new(from.clone()));
+    assert_eq!(smi.write_diff(&from, &to).unwrap(), 2);
+    assert_eq!(smi.0.writes(), 2);
+    assert_eq!(smi.0.reads(), 0);
+    assert!(smi.0.inner().eq_writable(&to));
+    assert_eq!(smi.write_diff(&to, &to).unwrap(), 0);
+}
+
+#[test]
+fn smi_write_diff_skips_side_effects() {
+    let from = smi::Map::default();
+    let mut to = from.clone();
+    to.indirect_access_ctrl1_mut().write().bits(0x01);
+    to.link_change_interrupt_mut().write().p2().set_bit();
+    to.reset_mut().write().pcs().set_bit();
+    to.gc3_mut().write().vlan().set_bit();
+    let mut smi = Smi(RecordWrites::default());
+    assert_eq!(smi.write_diff(&from, &to).unwrap(), 1);
+    assert_eq!(smi.0.addrs, vec![u8::from(smi::Address::Gc3)]);
+}
+
+#[cfg(feature = "journal")]
+#[test]
+fn smi_journaled_replay() {
+    let mut smi = Smi(smi::Journaled::<_, 4>::new(smi::Map::default()));
+    smi.gc3().modify(|w| w.vlan().set_bit()).unwrap();
+    smi.set_tail_tag(true).unwrap();
+    smi.software_reset().unwrap();
+    assert_eq!(smi.0.journal().len(), 3);
+    assert!(smi
+        .0
+        .journal()
+        .iter()
+        .all(|t| t.kind == ksz8863::TransactionKind::Write));
+    assert!(!smi.0.overflowed());
+
+    let mut other = Smi(smi::Map::default());
+    smi.0.replay(&mut other).unwrap();
+    assert_eq!(&other.0, smi.0.inner());
+
+    smi.set_tail_tag(false).unwrap();
+    smi.set_tail_tag(true).unwrap();
+    assert_eq!(smi.0.journal().len(), 4);
+    assert!(smi.0.overflowed());
+    smi.0.clear();
+    assert!(smi.0.journal().is_empty());
+}
+
+#[test]
+fn smi_read_write_reg() {
+    let mut smi = Smi(smi::Map::default());
+    let gc1: smi::Gc1 = smi.read_reg::<smi::Gc1>().unwrap();
+    assert_eq!(gc1, smi.gc1().read().unwrap());
+    let mut gc1 = gc1;
+    gc1.write().aging().clear_bit();
+    smi.write_reg(gc1).unwrap();
+    assert!(!smi
+        .read_reg::<smi::Gc1>()
+        .unwrap()
+        .read()
+        .aging()
+        .bit_is_set());
+}
+
+#[test]
+fn smi_phy_addrs() {
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.phy_addrs().unwrap(), ksz8863::miim::DEFAULT_PHY_ADDRS);
+    smi.set_phy_base_addr(0x08).unwrap();
+    assert_eq!(smi.gc13().read().unwrap().read().phy_addr().bits(), 0x08);
+    assert_eq!(smi.phy_addrs().unwrap(), [0x08, 0x09]);
+}
+
+#[test]
+fn smi_reset_all() {
+    let mut smi = Smi(smi::Map::default());
+    smi.gc1().modify(|w| w.aging().clear_bit()).unwrap();
+    smi.set_tail_tag(true).unwrap();
+    smi.set_start_switch(false).unwrap();
+    assert!(!smi.0.eq_writable(&smi::Map::default()));
+    smi.reset_all().unwrap();
+    assert!(smi.0.eq_writable(&smi::Map::default()));
+}
+
+#[test]
+fn smi_reset_all_skips_indirect_trigger() {
+    let mut smi = Smi(RecordWrites::default());
+    smi.indirect_data0().write(|w| w.data().bits(0xAB)).unwrap();
+    smi.0.addrs.clear();
+    smi.reset_all().unwrap();
+    for addr in NO_BULK_WRITE.iter() {
+        assert!(!smi.0.addrs.contains(&u8::from(*addr)));
+    }
+    // The indirect data registers are still restored, in address order.
+    let data0 = u8::from(smi::Address::IndirectData0);
+    let data8 = u8::from(smi::Address::IndirectData8);
+    let data: Vec<u8> = smi
+        .0
+        .addrs
+        .iter()
+        .cloned()
+        .filter(|addr| (data8..=data0).contains(addr))
+        .collect();
+    assert_eq!(data, (data8..=data0).collect::<Vec<_>>());
+    assert_eq!(smi.indirect_data0().read().unwrap().read().data().bits(), 0);
+}
+
+#[test]
+fn smi_gc12_drive_strength() {
+    use smi::DriveStrength;
+    let mut smi = Smi(smi::Map::default());
+    let gc12 = smi.gc12().read().unwrap();
+    assert_eq!(gc12.read().drive_strength().variant(), DriveStrength::Ma8);
+    smi.gc12()
+        .modify(|w| w.drive_strength().variant(DriveStrength::Ma4))
+        .unwrap();
+    let gc12 = smi.gc12().read().unwrap();
+    assert!(gc12.read().drive_strength().bit_is_clear());
+    assert_eq!(gc12.read().drive_strength().variant(), DriveStrength::Ma4);
+}
+
+#[test]
+fn smi_unknown_packet_default_port() {
+    use smi::{PortId, PortSet};
+    let mut smi = Smi(smi::Map::default());
+    assert_eq!(smi.unknown_packet_default_port().unwrap(), None);
+    smi.set_unknown_packet_default_port(Some(PortId::Port3.into()))
+        .unwrap();
+    let gc12 = smi.gc12().read().unwrap();
+    assert!(gc12
+        .read()
+        .unknown_packet_default_port_enable()
+        .bit_is_set());
+    assert_eq!(gc12.read().unknown_packet_default_port().bits(), 0b100);
+    assert_eq!(
+        smi.unknown_packet_default_port().unwrap(),
+        Some(PortSet::single(PortId::Port3))
+    );
+    smi.set_unknown_packet_default_port(None).unwrap();
+    assert_eq!(smi.unknown_packet_default_port().unwrap(), None);
+    assert!(smi
+        .gc12()
+        .read()
+        .unwrap()
+        .read()
+        .drive_strength()
+        .bit_is_set());
+}
+
+#[test]
+fn smi_set_internal_ldo() {
+    let mut smi = Smi(smi::Map::default());
+    assert!(smi.internal_ldo().unwrap());
+    smi.set_internal_ldo(false).unwrap();
+    let ctrl = smi.internal_ldo_ctrl().read().unwrap();
+    assert!(ctrl.read().disable().bit_is_set());
+    assert!(!smi.internal_ldo().unwrap());
+    smi.set_internal_ldo(true).unwrap();
+    let ctrl = smi.internal_ldo_ctrl().read().unwrap();
+    assert!(ctrl.read().disable().bit_is_clear());
+}
+
+#[test]
+fn smi_modify_verified() {
+    // A device that ignores all writes.
+    struct Locked(smi::Map);
+    impl smi::Read for Locked {
+        type Error = ksz8863::InvalidAddress;
+        fn read(&mut self, reg_addr: u8) -> Result<u8, Self::Error> {
+            self.0.read(reg_addr)
+        }
+    }
+    impl smi::Write for Locked {
+        type Error = ksz8863::InvalidAddress;
+        fn write(&mut self, _reg_addr: u8, _data: u8) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    let mut smi = Smi(smi::Map::default());
+    smi.gc1()
+        .modify_verified(|w| w.aging().clear_bit())
+        .unwrap();
+    assert!(smi.gc1().read().unwrap().read().aging().bit_is_clear());
+
+    // Self-clearing bits do not cause a mismatch once cleared by the device.
+    use smi::Register;
+    assert_eq!(smi::Gc0::SELF_CLEARING_MASK, 0b0011_0000);
+    assert_eq!(smi::Gc1::SELF_CLEARING_MASK, 0);
+    let mut sim = Smi(smi::Sim::default());
+    sim.gc0()
+        .modify_verified(|w| w.flush_dynamic_mac_table().set_bit())
+        .unwrap();
+    assert!(sim
+        .gc0()
+        .read()
+        .unwrap()
+        .read()
+        .flush_dynamic_mac_table()
+        .bit_is_clear());
+    sim.port2_ctrl13()
+        .modify_verified(|w| w.restart_an().set_bit())
+        .unwrap();
+    sim.port1_phy_special()
+        .modify_verified(|w| w.vct_en().set_bit())
+        .unwrap();
+    sim.reset().modify_verified(|w| w.pcs().set_bit()).unwrap();
+
+    let mut locked = Smi(Locked(smi::Map::default()));
+    locked.gc1().modify_verified(|w| w).unwrap();
+    match locked.gc1().modify_verified(|w| w.aging().clear_bit()) {
+        Err(ksz8863::VerifyError::Mismatch) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn smi_map_try_reg() {
+    let mut map = smi::Map::default();
+    map.reg_mut::<smi::Gc1>().write().aging().clear_bit();
+    assert_eq!(map.try_reg::<smi::Gc1>(), Some(map.reg::<smi::Gc1>()));
+    let state = map.state(smi::Address::Gc1);
+    assert_eq!(state.as_reg::<smi::Gc1>(), Some(map.reg::<smi::Gc1>()));
+    assert!(state.as_reg::<smi::Gc2>().is_none());
+}
+
+#[test]
+fn port_set_ops() {
+    use smi::PortId::{Port1, Port2, Port3};
+    use smi::PortSet;
+    let a = PortSet::from([Port1, Port2]);
+    let b = PortSet::from([Port2, Port3]);
+    assert_eq!(a.bits(), 0b011);
+    assert_eq!(b.bits(), 0b110);
+    assert_eq!((a | b), PortSet::ALL);
+    assert_eq!((a & b), PortSet::single(Port2));
+    assert_eq!(!a, PortSet::single(Port3));
+    assert_eq!((!PortSet::EMPTY).bits(), 0b111);
+    assert_eq!(!PortSet::ALL, PortSet::EMPTY);
+    let mut c = PortSet::EMPTY;
+    c |= Port1.into();
+    c |= Port3.into();
+    assert_eq!(c.bits(), 0b101);
+    c &= a;
+    assert_eq!(c, PortSet::single(Port1));
+    let collected: PortSet = vec![Port3, Port1, Port3].into_iter().collect();
+    assert_eq!(collected.bits(), 0b101);
+    let ids: Vec<_> = collected.into_iter().collect();
+    assert_eq!(ids, vec![Port1, Port3]);
+    assert_eq!(PortSet::from([]).iter().count(), 0);
+}
+
+#[test]
+fn indirect_addr_out_of_range() {
+    use smi::{IndirectError, Table};
+    let mut smi = Smi(RecordWrites::default());
+    let max = smi::Indirect::<()>::MAX_ADDR;
+    smi.indirect().read(Table::DynamicMac, max).unwrap();
+    smi.indirect()
+        .write(Table::StaticMac, max, &[0; 9])
+        .unwrap();
+    smi.0.addrs.clear();
+    assert!(matches!(
+        smi.indirect().read(Table::DynamicMac, max + 1),
+        Err(IndirectError::EntryOutOfRange)
+    ));
+    assert!(matches!(
+        smi.indirect().write(Table::StaticMac, 0x400, &[0; 9]),
+        Err(IndirectError::EntryOutOfRange)
+    ));
+    assert!(smi.0.addrs.is_empty());
+}
+
+#[test]
+fn static_mac_table_index_out_of_range() {
+    use smi::IndirectError;
+    let mut smi = Smi(RecordWrites::default());
+    let entry = smi::StaticMacEntry::default();
+    smi.static_mac_table().write_entry(7, &entry).unwrap();
+    smi.0.addrs.clear();
+    assert!(matches!(
+        smi.static_mac_table().read_entry(8),
+        Err(IndirectError::EntryOutOfRange)
+    ));
+    assert!(matches!(
+        smi.static_mac_table().write_entry(8, &entry),
+        Err(IndirectError::EntryOutOfRange)
+    ));
+    assert!(smi.0.addrs.is_empty());
+}
+
+#[test]
+fn dynamic_mac_table_errors() {
+    use smi::IndirectError;
+    let mut smi = Smi(smi::Map::default());
+    let capacity = smi::DynamicMacTable::<()>::CAPACITY;
+    assert!(matches!(
+        smi.dynamic_mac_table().entry(capacity),
+        Err(IndirectError::EntryOutOfRange)
+    ));
+    // The "data not ready" bit never clears.
+    smi.0.indirect_data8_mut().write().bits(0b1000_0000);
+    assert!(matches!(
+        smi.dynamic_mac_table().entry(capacity - 1),
+        Err(IndirectError::Timeout)
+    ));
+    assert!(matches!(
+        smi.dynamic_mac_table().len(),
+        Err(IndirectError::Timeout)
+    ));
+}